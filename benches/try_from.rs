@@ -0,0 +1,212 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Defines the same enum in a module for each lookup strategy, and in a
+// `guards` module with the chain of match guards generated before the
+// strategies were introduced.
+macro_rules! opcodes {
+    ($type:ident { $($variant:ident = $value:literal,)* }) => {
+        pub mod guards {
+            #[repr($type)]
+            #[derive(Clone, Copy)]
+            pub enum Opcode {
+                $($variant = $value,)*
+            }
+
+            impl TryFrom<$type> for Opcode {
+                type Error = ();
+
+                fn try_from(v: $type) -> Result<Self, Self::Error> {
+                    match v {
+                        $(x if x == Opcode::$variant as $type => Ok(Opcode::$variant),)*
+                        _ => Err(()),
+                    }
+                }
+            }
+        }
+
+        opcodes!(@strategy auto "auto" $type { $($variant = $value,)* });
+        opcodes!(@strategy matches "match" $type { $($variant = $value,)* });
+        opcodes!(@strategy table "table" $type { $($variant = $value,)* });
+        opcodes!(@strategy binary_search "binary_search" $type { $($variant = $value,)* });
+    };
+    (@strategy $module:ident $strategy:tt $type:ident { $($variant:ident = $value:literal,)* }) => {
+        pub mod $module {
+            enum_try_from::impl_enum_try_from!(
+                #[try_from(strategy = $strategy)]
+                #[repr($type)]
+                #[derive(Clone, Copy)]
+                pub enum Opcode {
+                    $($variant = $value,)*
+                },
+                $type,
+                (),
+                ()
+            );
+        }
+    };
+}
+
+/// 200 variants with contiguous discriminants.
+mod dense {
+    opcodes!(u8 {
+        Op000 = 0x00, Op001 = 0x01, Op002 = 0x02, Op003 = 0x03, Op004 = 0x04, Op005 = 0x05,
+        Op006 = 0x06, Op007 = 0x07, Op008 = 0x08, Op009 = 0x09, Op010 = 0x0a, Op011 = 0x0b,
+        Op012 = 0x0c, Op013 = 0x0d, Op014 = 0x0e, Op015 = 0x0f, Op016 = 0x10, Op017 = 0x11,
+        Op018 = 0x12, Op019 = 0x13, Op020 = 0x14, Op021 = 0x15, Op022 = 0x16, Op023 = 0x17,
+        Op024 = 0x18, Op025 = 0x19, Op026 = 0x1a, Op027 = 0x1b, Op028 = 0x1c, Op029 = 0x1d,
+        Op030 = 0x1e, Op031 = 0x1f, Op032 = 0x20, Op033 = 0x21, Op034 = 0x22, Op035 = 0x23,
+        Op036 = 0x24, Op037 = 0x25, Op038 = 0x26, Op039 = 0x27, Op040 = 0x28, Op041 = 0x29,
+        Op042 = 0x2a, Op043 = 0x2b, Op044 = 0x2c, Op045 = 0x2d, Op046 = 0x2e, Op047 = 0x2f,
+        Op048 = 0x30, Op049 = 0x31, Op050 = 0x32, Op051 = 0x33, Op052 = 0x34, Op053 = 0x35,
+        Op054 = 0x36, Op055 = 0x37, Op056 = 0x38, Op057 = 0x39, Op058 = 0x3a, Op059 = 0x3b,
+        Op060 = 0x3c, Op061 = 0x3d, Op062 = 0x3e, Op063 = 0x3f, Op064 = 0x40, Op065 = 0x41,
+        Op066 = 0x42, Op067 = 0x43, Op068 = 0x44, Op069 = 0x45, Op070 = 0x46, Op071 = 0x47,
+        Op072 = 0x48, Op073 = 0x49, Op074 = 0x4a, Op075 = 0x4b, Op076 = 0x4c, Op077 = 0x4d,
+        Op078 = 0x4e, Op079 = 0x4f, Op080 = 0x50, Op081 = 0x51, Op082 = 0x52, Op083 = 0x53,
+        Op084 = 0x54, Op085 = 0x55, Op086 = 0x56, Op087 = 0x57, Op088 = 0x58, Op089 = 0x59,
+        Op090 = 0x5a, Op091 = 0x5b, Op092 = 0x5c, Op093 = 0x5d, Op094 = 0x5e, Op095 = 0x5f,
+        Op096 = 0x60, Op097 = 0x61, Op098 = 0x62, Op099 = 0x63, Op100 = 0x64, Op101 = 0x65,
+        Op102 = 0x66, Op103 = 0x67, Op104 = 0x68, Op105 = 0x69, Op106 = 0x6a, Op107 = 0x6b,
+        Op108 = 0x6c, Op109 = 0x6d, Op110 = 0x6e, Op111 = 0x6f, Op112 = 0x70, Op113 = 0x71,
+        Op114 = 0x72, Op115 = 0x73, Op116 = 0x74, Op117 = 0x75, Op118 = 0x76, Op119 = 0x77,
+        Op120 = 0x78, Op121 = 0x79, Op122 = 0x7a, Op123 = 0x7b, Op124 = 0x7c, Op125 = 0x7d,
+        Op126 = 0x7e, Op127 = 0x7f, Op128 = 0x80, Op129 = 0x81, Op130 = 0x82, Op131 = 0x83,
+        Op132 = 0x84, Op133 = 0x85, Op134 = 0x86, Op135 = 0x87, Op136 = 0x88, Op137 = 0x89,
+        Op138 = 0x8a, Op139 = 0x8b, Op140 = 0x8c, Op141 = 0x8d, Op142 = 0x8e, Op143 = 0x8f,
+        Op144 = 0x90, Op145 = 0x91, Op146 = 0x92, Op147 = 0x93, Op148 = 0x94, Op149 = 0x95,
+        Op150 = 0x96, Op151 = 0x97, Op152 = 0x98, Op153 = 0x99, Op154 = 0x9a, Op155 = 0x9b,
+        Op156 = 0x9c, Op157 = 0x9d, Op158 = 0x9e, Op159 = 0x9f, Op160 = 0xa0, Op161 = 0xa1,
+        Op162 = 0xa2, Op163 = 0xa3, Op164 = 0xa4, Op165 = 0xa5, Op166 = 0xa6, Op167 = 0xa7,
+        Op168 = 0xa8, Op169 = 0xa9, Op170 = 0xaa, Op171 = 0xab, Op172 = 0xac, Op173 = 0xad,
+        Op174 = 0xae, Op175 = 0xaf, Op176 = 0xb0, Op177 = 0xb1, Op178 = 0xb2, Op179 = 0xb3,
+        Op180 = 0xb4, Op181 = 0xb5, Op182 = 0xb6, Op183 = 0xb7, Op184 = 0xb8, Op185 = 0xb9,
+        Op186 = 0xba, Op187 = 0xbb, Op188 = 0xbc, Op189 = 0xbd, Op190 = 0xbe, Op191 = 0xbf,
+        Op192 = 0xc0, Op193 = 0xc1, Op194 = 0xc2, Op195 = 0xc3, Op196 = 0xc4, Op197 = 0xc5,
+        Op198 = 0xc6, Op199 = 0xc7,
+    });
+}
+
+/// 200 variants with discriminants spread over the whole `u16` range.
+mod sparse {
+    opcodes!(u16 {
+        Op000 = 0x0000, Op001 = 0x013f, Op002 = 0x027e, Op003 = 0x03bd, Op004 = 0x04fc, Op005 = 0x063b,
+        Op006 = 0x077a, Op007 = 0x08b9, Op008 = 0x09f8, Op009 = 0x0b37, Op010 = 0x0c76, Op011 = 0x0db5,
+        Op012 = 0x0ef4, Op013 = 0x1033, Op014 = 0x1172, Op015 = 0x12b1, Op016 = 0x13f0, Op017 = 0x152f,
+        Op018 = 0x166e, Op019 = 0x17ad, Op020 = 0x18ec, Op021 = 0x1a2b, Op022 = 0x1b6a, Op023 = 0x1ca9,
+        Op024 = 0x1de8, Op025 = 0x1f27, Op026 = 0x2066, Op027 = 0x21a5, Op028 = 0x22e4, Op029 = 0x2423,
+        Op030 = 0x2562, Op031 = 0x26a1, Op032 = 0x27e0, Op033 = 0x291f, Op034 = 0x2a5e, Op035 = 0x2b9d,
+        Op036 = 0x2cdc, Op037 = 0x2e1b, Op038 = 0x2f5a, Op039 = 0x3099, Op040 = 0x31d8, Op041 = 0x3317,
+        Op042 = 0x3456, Op043 = 0x3595, Op044 = 0x36d4, Op045 = 0x3813, Op046 = 0x3952, Op047 = 0x3a91,
+        Op048 = 0x3bd0, Op049 = 0x3d0f, Op050 = 0x3e4e, Op051 = 0x3f8d, Op052 = 0x40cc, Op053 = 0x420b,
+        Op054 = 0x434a, Op055 = 0x4489, Op056 = 0x45c8, Op057 = 0x4707, Op058 = 0x4846, Op059 = 0x4985,
+        Op060 = 0x4ac4, Op061 = 0x4c03, Op062 = 0x4d42, Op063 = 0x4e81, Op064 = 0x4fc0, Op065 = 0x50ff,
+        Op066 = 0x523e, Op067 = 0x537d, Op068 = 0x54bc, Op069 = 0x55fb, Op070 = 0x573a, Op071 = 0x5879,
+        Op072 = 0x59b8, Op073 = 0x5af7, Op074 = 0x5c36, Op075 = 0x5d75, Op076 = 0x5eb4, Op077 = 0x5ff3,
+        Op078 = 0x6132, Op079 = 0x6271, Op080 = 0x63b0, Op081 = 0x64ef, Op082 = 0x662e, Op083 = 0x676d,
+        Op084 = 0x68ac, Op085 = 0x69eb, Op086 = 0x6b2a, Op087 = 0x6c69, Op088 = 0x6da8, Op089 = 0x6ee7,
+        Op090 = 0x7026, Op091 = 0x7165, Op092 = 0x72a4, Op093 = 0x73e3, Op094 = 0x7522, Op095 = 0x7661,
+        Op096 = 0x77a0, Op097 = 0x78df, Op098 = 0x7a1e, Op099 = 0x7b5d, Op100 = 0x7c9c, Op101 = 0x7ddb,
+        Op102 = 0x7f1a, Op103 = 0x8059, Op104 = 0x8198, Op105 = 0x82d7, Op106 = 0x8416, Op107 = 0x8555,
+        Op108 = 0x8694, Op109 = 0x87d3, Op110 = 0x8912, Op111 = 0x8a51, Op112 = 0x8b90, Op113 = 0x8ccf,
+        Op114 = 0x8e0e, Op115 = 0x8f4d, Op116 = 0x908c, Op117 = 0x91cb, Op118 = 0x930a, Op119 = 0x9449,
+        Op120 = 0x9588, Op121 = 0x96c7, Op122 = 0x9806, Op123 = 0x9945, Op124 = 0x9a84, Op125 = 0x9bc3,
+        Op126 = 0x9d02, Op127 = 0x9e41, Op128 = 0x9f80, Op129 = 0xa0bf, Op130 = 0xa1fe, Op131 = 0xa33d,
+        Op132 = 0xa47c, Op133 = 0xa5bb, Op134 = 0xa6fa, Op135 = 0xa839, Op136 = 0xa978, Op137 = 0xaab7,
+        Op138 = 0xabf6, Op139 = 0xad35, Op140 = 0xae74, Op141 = 0xafb3, Op142 = 0xb0f2, Op143 = 0xb231,
+        Op144 = 0xb370, Op145 = 0xb4af, Op146 = 0xb5ee, Op147 = 0xb72d, Op148 = 0xb86c, Op149 = 0xb9ab,
+        Op150 = 0xbaea, Op151 = 0xbc29, Op152 = 0xbd68, Op153 = 0xbea7, Op154 = 0xbfe6, Op155 = 0xc125,
+        Op156 = 0xc264, Op157 = 0xc3a3, Op158 = 0xc4e2, Op159 = 0xc621, Op160 = 0xc760, Op161 = 0xc89f,
+        Op162 = 0xc9de, Op163 = 0xcb1d, Op164 = 0xcc5c, Op165 = 0xcd9b, Op166 = 0xceda, Op167 = 0xd019,
+        Op168 = 0xd158, Op169 = 0xd297, Op170 = 0xd3d6, Op171 = 0xd515, Op172 = 0xd654, Op173 = 0xd793,
+        Op174 = 0xd8d2, Op175 = 0xda11, Op176 = 0xdb50, Op177 = 0xdc8f, Op178 = 0xddce, Op179 = 0xdf0d,
+        Op180 = 0xe04c, Op181 = 0xe18b, Op182 = 0xe2ca, Op183 = 0xe409, Op184 = 0xe548, Op185 = 0xe687,
+        Op186 = 0xe7c6, Op187 = 0xe905, Op188 = 0xea44, Op189 = 0xeb83, Op190 = 0xecc2, Op191 = 0xee01,
+        Op192 = 0xef40, Op193 = 0xf07f, Op194 = 0xf1be, Op195 = 0xf2fd, Op196 = 0xf43c, Op197 = 0xf57b,
+        Op198 = 0xf6ba, Op199 = 0xf7f9,
+    });
+}
+
+/// Pseudo-random values lower than `modulus`.
+fn values(modulus: u64) -> impl Iterator<Item = u64> {
+    let mut state: u64 = 0x2545f4914f6cdd1d;
+    (0..1024).map(move |_| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) % modulus
+    })
+}
+
+macro_rules! bench_strategies {
+    ($c:ident, $group:literal, $enums:ident, $values:expr) => {
+        let values = $values;
+        let mut group = $c.benchmark_group($group);
+        group.bench_function("guards", |b| {
+            b.iter(|| {
+                values
+                    .iter()
+                    .filter(|&&v| $enums::guards::Opcode::try_from(black_box(v)).is_ok())
+                    .count()
+            })
+        });
+        group.bench_function("auto", |b| {
+            b.iter(|| {
+                values
+                    .iter()
+                    .filter(|&&v| $enums::auto::Opcode::try_from(black_box(v)).is_ok())
+                    .count()
+            })
+        });
+        group.bench_function("match", |b| {
+            b.iter(|| {
+                values
+                    .iter()
+                    .filter(|&&v| $enums::matches::Opcode::try_from(black_box(v)).is_ok())
+                    .count()
+            })
+        });
+        group.bench_function("table", |b| {
+            b.iter(|| {
+                values
+                    .iter()
+                    .filter(|&&v| $enums::table::Opcode::try_from(black_box(v)).is_ok())
+                    .count()
+            })
+        });
+        group.bench_function("binary_search", |b| {
+            b.iter(|| {
+                values
+                    .iter()
+                    .filter(|&&v| $enums::binary_search::Opcode::try_from(black_box(v)).is_ok())
+                    .count()
+            })
+        });
+        group.finish();
+    };
+}
+
+fn bench_dense(c: &mut Criterion) {
+    // About 80% of the values are discriminants.
+    let values: Vec<u8> = values(256).map(|v| v as u8).collect();
+    bench_strategies!(c, "dense", dense, values);
+}
+
+fn bench_sparse(c: &mut Criterion) {
+    // Every other value is a discriminant.
+    let values: Vec<u16> = values(1 << 16)
+        .enumerate()
+        .map(|(i, v)| {
+            if i % 2 == 0 {
+                (v % 200) as u16 * 0x13f
+            } else {
+                v as u16
+            }
+        })
+        .collect();
+    bench_strategies!(c, "sparse", sparse, values);
+}
+
+criterion_group!(benches, bench_dense, bench_sparse);
+criterion_main!(benches);
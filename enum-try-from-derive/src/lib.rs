@@ -0,0 +1,598 @@
+//! Derive macro which implements the `TryFrom` trait for enums. It's a
+//! companion of the [`enum-try-from`](https://docs.rs/enum-try-from) crate and
+//! generates the same code as its `impl_enum_try_from` macros, but doesn't
+//! require wrapping the enum definition inside a macro call.
+//!
+//! The generated code refers to the `enum_try_from` crate, which has to be a
+//! dependency as well. The derive macro is also re-exported from it with the
+//! `derive` feature enabled.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use enum_try_from::TryFromReprError;
+//! use enum_try_from_derive::TryFromRepr;
+//!
+//! #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+//! #[repr(u16)]
+//! enum MyEnum {
+//!     Foo = 0,
+//!     Bar = 1,
+//!     Baz = 2,
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(MyEnum::try_from(0), Ok(MyEnum::Foo));
+//!     assert_eq!(MyEnum::try_from(1), Ok(MyEnum::Bar));
+//!     assert_eq!(MyEnum::try_from(2), Ok(MyEnum::Baz));
+//!     assert_eq!(
+//!         MyEnum::try_from(3),
+//!         Err(TryFromReprError {
+//!             value: 3,
+//!             enum_name: "MyEnum",
+//!             valid_values: &[0, 1, 2],
+//!             valid_ranges: &[],
+//!         })
+//!     );
+//! }
+//! ```
+//!
+//! ```rust
+//! use enum_try_from_derive::TryFromRepr;
+//! use thiserror::Error;
+//!
+//! #[derive(Error, Debug, PartialEq, Eq)]
+//! pub enum MyError {
+//!     #[error("invalid value")]
+//!     InvalidValue,
+//! }
+//!
+//! #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+//! #[try_from(error = MyError::InvalidValue, endian = "be")]
+//! #[repr(u16)]
+//! enum MyEnum {
+//!     Foo = 0x1234,
+//!     Bar = 0x5678,
+//!     Baz = 0x9abc,
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(MyEnum::try_from(0x1234_u16.to_be()), Ok(MyEnum::Foo));
+//!     assert_eq!(MyEnum::try_from(0x5678_u16.to_be()), Ok(MyEnum::Bar));
+//!     assert_eq!(MyEnum::try_from(0x9abc_u16.to_be()), Ok(MyEnum::Baz));
+//!     assert_eq!(MyEnum::try_from(0xdef0_u16.to_be()), Err(MyError::InvalidValue));
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Attribute, Data, DeriveInput, Error, Expr,
+    ExprRange, Fields, LitStr, Meta, Path, RangeLimits, Token, Type,
+};
+
+/// Primitive integer types which can be used in `#[repr(...)]`.
+const PRIMITIVES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Derive macro which implements the `TryFrom` trait for the given enum.
+///
+/// The generated code is configured with the `#[try_from(...)]` attribute on
+/// the enum. The available options are:
+///
+/// * `repr = u16` - the type to implement the trait for. If not provided, the
+///   primitive integer type from the `#[repr(...)]` attribute is used.
+/// * `error = MyError::InvalidValue` - the concrete error value which should be
+///   returned if the value provided to `try_from` is not a valid variant of the
+///   enum. It can also be a closure, i.e. `|v| MyError::UnknownOpcode(v)`, which
+///   is called with the value provided to `try_from`, or with both that value
+///   and the value converted to native byte order if it takes two arguments.
+///   If no error is provided, `TryFromReprError` is returned, holding the value
+///   in native byte order, the name of the enum and its discriminants.
+/// * `error_fn = MyError::UnknownOpcode` - a function which is called with the
+///   value provided to `try_from` to create the error, instead of `error`.
+/// * `error_type = MyError` - the type of the error. If not provided, it's
+///   inferred from the path of `error` or `error_fn`.
+/// * `endian = "ne"`, `"be"`, `"le"` or `"runtime"` - the byte order of the
+///   value provided to `try_from`, same as in `impl_enum_try_from`. Defaults to
+///   `"ne"`.
+///
+/// * `impl_default` - implement `Default` returning the variant marked with
+///   `#[try_from(default)]`.
+/// * `strategy = "auto"`, `"match"`, `"table"` or `"binary_search"` - how the
+///   discriminants are looked up, same as in `impl_enum_try_from`. Defaults to
+///   `"auto"`.
+/// * `from_str = "exact"`, `"case_insensitive"`, `"snake_case"` or
+///   `"kebab_case"` - implement `FromStr` and `TryFrom<&str>` converting the
+///   names of the unit variants, same as in `impl_enum_try_from`. `from_str`
+///   alone is the same as `"exact"`.
+/// * `display` - implement `Display` printing the names of the variants.
+/// * `debug` - implement `Debug` printing the names and the values of the
+///   variants, i.e. `Foo(0x1234)`.
+/// * `hex` - implement `LowerHex` and `UpperHex` formatting the values of the
+///   variants.
+/// * `serde = "value"`, `"name"` or `"lenient"` - implement `Serialize` and
+///   `Deserialize`, same as in `impl_enum_try_from`. Requires the `serde`
+///   feature of `enum-try-from`. `serde` alone is the same as `"value"`.
+/// * `bytemuck` or `bytemuck = "contiguous"` - implement
+///   `bytemuck::CheckedBitPattern` and `bytemuck::NoUninit`, and also
+///   `bytemuck::Contiguous` with `"contiguous"`, same as in
+///   `impl_enum_try_from`. Requires the `bytemuck` feature of `enum-try-from`.
+///   The `zerocopy` option of `impl_enum_try_from` isn't supported, as a derive
+///   macro can't add derives to the enum.
+/// * `deprecated_alias_hook = path::to::function` - the function which is
+///   called with the value in native byte order and a reference to the variant
+///   when a deprecated alias is converted.
+/// * `consts` - define the `ALL`, `VALUES`, `COUNT`, `MIN` and `MAX` associated
+///   constants with the unit variants and their discriminants, same as in
+///   `impl_enum_try_from`.
+///
+/// A tuple variant with a single field can be marked with `#[try_from(other)]`
+/// to hold the values which don't match any other variant. Alternatively, a
+/// unit variant can be marked with `#[try_from(default)]` to be returned for
+/// such values. `From` is then implemented instead of `TryFrom` and the error
+/// options can't be used.
+///
+/// A variant marked with `#[try_from(range = 0x10..=0x1f)]` matches all values
+/// in the inclusive range. A unit variant is converted back to the start of the
+/// range, while a tuple variant with a single field holds the value. The ranges
+/// can't overlap with other variants.
+///
+/// A unit variant can accept additional values with
+/// `#[try_from(alias = 0x05)]` or `#[try_from(deprecated_alias = 0x05)]`, while
+/// being converted back to its discriminant.
+///
+/// With the `from_str` option, a unit variant can be converted from another
+/// name with `#[try_from(rename = "foo")]` and from additional names with
+/// `#[try_from(name_alias = "legacy_foo")]`. The name from `rename` is also
+/// printed by `display` and `debug`.
+///
+/// The `EnumRepr` trait used by `MaybeKnown` is implemented the same way as by
+/// `impl_enum_try_from`.
+///
+/// The `from_repr` and `repr` const functions, which use the byte order of
+/// `try_from`, `from_ne_repr` and `ne_repr` for native byte order, and
+/// `from_be_repr` and `be_repr` or `from_le_repr` and `le_repr` with a fixed
+/// `endian`, are defined the same way as well.
+///
+/// The enum can be converted from bytes with `TryFrom<[u8; N]>`,
+/// `try_from_be_bytes`, `try_from_le_bytes`, `try_from_ne_bytes` and
+/// `try_read_from`, same as in `impl_enum_try_from`.
+#[proc_macro_derive(TryFromRepr, attributes(try_from))]
+pub fn derive_try_from_repr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options provided in the `#[try_from(...)]` attribute.
+struct Options {
+    repr: Option<Type>,
+    error_type: Option<Type>,
+    error: Option<Expr>,
+    error_fn: Option<Expr>,
+    endian: Ident,
+    impl_default: bool,
+    strategy: Ident,
+    from_str: Option<Ident>,
+    display: bool,
+    debug: bool,
+    hex: bool,
+    serde: Ident,
+    bytemuck: Ident,
+    deprecated_alias_hook: Option<Path>,
+    consts: bool,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut options = Options {
+            repr: None,
+            error_type: None,
+            error: None,
+            error_fn: None,
+            endian: Ident::new("ne", Span::call_site()),
+            impl_default: false,
+            strategy: Ident::new("auto", Span::call_site()),
+            from_str: None,
+            display: false,
+            debug: false,
+            hex: false,
+            serde: Ident::new("no", Span::call_site()),
+            bytemuck: Ident::new("no", Span::call_site()),
+            deprecated_alias_hook: None,
+            consts: false,
+        };
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("try_from")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("repr") {
+                    options.repr = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("error_type") {
+                    options.error_type = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("error") {
+                    options.error = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("error_fn") {
+                    options.error_fn = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("endian") {
+                    let endian: LitStr = meta.value()?.parse()?;
+                    match endian.value().as_str() {
+                        "ne" | "be" | "le" | "runtime" => {
+                            options.endian = Ident::new(&endian.value(), endian.span());
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                endian.span(),
+                                "expected `\"ne\"`, `\"be\"`, `\"le\"` or `\"runtime\"`",
+                            ))
+                        }
+                    }
+                } else if meta.path.is_ident("impl_default") {
+                    options.impl_default = true;
+                } else if meta.path.is_ident("strategy") {
+                    let strategy: LitStr = meta.value()?.parse()?;
+                    match strategy.value().as_str() {
+                        "auto" | "match" | "table" | "binary_search" => {
+                            options.strategy = Ident::new(&strategy.value(), strategy.span());
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                strategy.span(),
+                                "expected `\"auto\"`, `\"match\"`, `\"table\"` or \
+                                 `\"binary_search\"`",
+                            ))
+                        }
+                    }
+                } else if meta.path.is_ident("from_str") {
+                    let case = if meta.input.peek(Token![=]) {
+                        let case: LitStr = meta.value()?.parse()?;
+                        match case.value().as_str() {
+                            "exact" => "Exact",
+                            "case_insensitive" => "CaseInsensitive",
+                            "snake_case" => "SnakeCase",
+                            "kebab_case" => "KebabCase",
+                            _ => {
+                                return Err(Error::new(
+                                    case.span(),
+                                    "expected `\"exact\"`, `\"case_insensitive\"`, \
+                                     `\"snake_case\"` or `\"kebab_case\"`",
+                                ))
+                            }
+                        }
+                    } else {
+                        "Exact"
+                    };
+                    options.from_str = Some(Ident::new(case, Span::call_site()));
+                } else if meta.path.is_ident("display") {
+                    options.display = true;
+                } else if meta.path.is_ident("debug") {
+                    options.debug = true;
+                } else if meta.path.is_ident("hex") {
+                    options.hex = true;
+                } else if meta.path.is_ident("serde") {
+                    let mode = if meta.input.peek(Token![=]) {
+                        let mode: LitStr = meta.value()?.parse()?;
+                        match mode.value().as_str() {
+                            "value" | "name" | "lenient" => mode.value(),
+                            _ => {
+                                return Err(Error::new(
+                                    mode.span(),
+                                    "expected `\"value\"`, `\"name\"` or `\"lenient\"`",
+                                ))
+                            }
+                        }
+                    } else {
+                        "value".to_owned()
+                    };
+                    options.serde = Ident::new(&mode, Span::call_site());
+                } else if meta.path.is_ident("bytemuck") {
+                    let mode = if meta.input.peek(Token![=]) {
+                        let mode: LitStr = meta.value()?.parse()?;
+                        if mode.value() != "contiguous" {
+                            return Err(Error::new(mode.span(), "expected `\"contiguous\"`"));
+                        }
+                        "contiguous"
+                    } else {
+                        "checked"
+                    };
+                    options.bytemuck = Ident::new(mode, Span::call_site());
+                } else if meta.path.is_ident("zerocopy") {
+                    return Err(meta.error(
+                        "the `zerocopy` option is not supported by the derive macro, derive the \
+                         `zerocopy` traits on the enum instead",
+                    ));
+                } else if meta.path.is_ident("deprecated_alias_hook") {
+                    options.deprecated_alias_hook = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("consts") {
+                    options.consts = true;
+                } else {
+                    return Err(meta.error("unknown `try_from` option"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Finds the primitive integer type in `#[repr(...)]` attributes.
+fn repr_type(attrs: &[Attribute]) -> syn::Result<Option<Type>> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if let Meta::Path(path) = meta {
+                if let Some(ident) = path.get_ident() {
+                    if PRIMITIVES.contains(&ident.to_string().as_str()) {
+                        return Ok(Some(Type::Path(syn::TypePath { qself: None, path })));
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Infers the type of the error from the path of the error value, i.e.
+/// `MyError` from `MyError::InvalidValue`, `MyError::InvalidValue(0)` or
+/// `|v| MyError::InvalidValue(v)`.
+fn infer_error_type(error: &Expr) -> syn::Result<Type> {
+    let path = match error {
+        Expr::Tuple(tuple) if tuple.elems.is_empty() => return Ok(syn::parse_quote!(())),
+        Expr::Closure(closure) => return infer_error_type(&closure.body),
+        Expr::Path(expr) => Some(&expr.path),
+        Expr::Call(call) => match &*call.func {
+            Expr::Path(expr) => Some(&expr.path),
+            _ => None,
+        },
+        _ => None,
+    };
+    match path {
+        Some(path) if path.segments.len() > 1 => {
+            let mut path = path.clone();
+            path.segments.pop();
+            path.segments.pop_punct();
+            Ok(Type::Path(syn::TypePath { qself: None, path }))
+        }
+        _ => Err(Error::new_spanned(
+            error,
+            "cannot infer the type of `error`, provide it with `error_type`",
+        )),
+    }
+}
+
+/// Options provided in the `#[try_from(...)]` attribute of a variant.
+struct VariantOptions {
+    /// `other` or `default`.
+    fallback: Option<Ident>,
+    /// Start and end of the inclusive range of values.
+    range: Option<(Expr, Expr)>,
+    aliases: Vec<Expr>,
+    deprecated_aliases: Vec<Expr>,
+    rename: Option<LitStr>,
+    name_aliases: Vec<LitStr>,
+}
+
+impl VariantOptions {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = VariantOptions {
+            fallback: None,
+            range: None,
+            aliases: Vec::new(),
+            deprecated_aliases: Vec::new(),
+            rename: None,
+            name_aliases: Vec::new(),
+        };
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("try_from")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("other") || meta.path.is_ident("default") {
+                    options.fallback = meta.path.get_ident().cloned();
+                } else if meta.path.is_ident("range") {
+                    let range: ExprRange = meta.value()?.parse()?;
+                    match range {
+                        ExprRange {
+                            start: Some(start),
+                            limits: RangeLimits::Closed(_),
+                            end: Some(end),
+                            ..
+                        } => options.range = Some((*start, *end)),
+                        _ => {
+                            return Err(Error::new_spanned(
+                                range,
+                                "expected an inclusive range, i.e. `0x10..=0x1f`",
+                            ))
+                        }
+                    }
+                } else if meta.path.is_ident("alias") {
+                    options.aliases.push(meta.value()?.parse()?);
+                } else if meta.path.is_ident("deprecated_alias") {
+                    options.deprecated_aliases.push(meta.value()?.parse()?);
+                } else if meta.path.is_ident("rename") {
+                    options.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("name_alias") {
+                    options.name_aliases.push(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown `try_from` variant option"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`TryFromRepr` can be derived only for enums",
+            ))
+        }
+    };
+    let mut discriminants = Vec::with_capacity(data.variants.len());
+    let mut units = Vec::new();
+    let mut unit_ranges = Vec::new();
+    let mut tuple_ranges = Vec::new();
+    let mut fallback = None;
+    let mut named_variant = None;
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let options = VariantOptions::parse(&variant.attrs)?;
+        if let Some(kind) = &options.fallback {
+            if fallback.is_some() {
+                return Err(Error::new_spanned(
+                    variant,
+                    "only one variant can be marked with `#[try_from(other)]` or \
+                     `#[try_from(default)]`",
+                ));
+            }
+            fallback = Some((kind.clone(), ident));
+        }
+        let unit = variant.fields.is_empty();
+        let single_field =
+            matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() == 1);
+        let aliases = &options.aliases;
+        let deprecated_aliases = &options.deprecated_aliases;
+        let rename = &options.rename;
+        let name_aliases = &options.name_aliases;
+        let has_aliases = !aliases.is_empty()
+            || !deprecated_aliases.is_empty()
+            || rename.is_some()
+            || !name_aliases.is_empty();
+        if has_aliases && !unit {
+            return Err(Error::new_spanned(
+                variant,
+                "only unit variants can have aliases and names",
+            ));
+        }
+        if rename.is_some() || !name_aliases.is_empty() {
+            named_variant.get_or_insert(variant);
+        }
+        let unit_variant = quote! {
+            #ident [#((#aliases),)*] [#((#deprecated_aliases),)*] [#rename] [#(#name_aliases,)*]
+        };
+        match (&options.fallback, &options.range) {
+            (Some(kind), None) if kind == "other" && single_field => {}
+            (Some(kind), None) if kind == "default" && unit => units.push(unit_variant),
+            (None, Some(_)) if has_aliases => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "variants with a range can't have aliases and names",
+                ))
+            }
+            (None, Some((start, end))) if unit => unit_ranges.push(quote!(#ident [#start, #end])),
+            (None, Some((start, end))) if single_field => {
+                tuple_ranges.push(quote!(#ident [#start, #end]))
+            }
+            (None, None) if unit => units.push(unit_variant),
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "`TryFromRepr` supports only unit variants, a unit variant marked with \
+                     `#[try_from(default)]`, a tuple variant with a single field marked with \
+                     `#[try_from(other)]` and variants marked with `#[try_from(range = ...)]`",
+                ))
+            }
+        }
+        discriminants.push(match &variant.discriminant {
+            Some((_, discriminant)) => quote!(#ident = #discriminant),
+            None => quote!(#ident),
+        });
+    }
+
+    let options = Options::parse(&input)?;
+    if let (Some(variant), None, false) = (named_variant, &options.from_str, options.display) {
+        return Err(Error::new_spanned(
+            variant,
+            "`rename` and `name_alias` require the `from_str` or `display` option",
+        ));
+    }
+    let primitive = repr_type(&input.attrs)?;
+    let repr = match options.repr.or_else(|| primitive.clone()) {
+        Some(repr) => repr,
+        None => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "cannot infer the type to convert from, provide it with \
+                 `#[try_from(repr = ...)]` or add a `#[repr(...)]` attribute with a primitive \
+                 integer type",
+            ))
+        }
+    };
+    let (error, error_fn) = match (options.error, options.error_fn) {
+        (Some(_), Some(error_fn)) => {
+            return Err(Error::new_spanned(
+                error_fn,
+                "`error` and `error_fn` can't be used together",
+            ))
+        }
+        (Some(error), None) => (Some(error), false),
+        (None, Some(error_fn)) => (Some(error_fn), true),
+        (None, None) => (None, false),
+    };
+    let error = match (options.error_type, error) {
+        (Some(error_type), Some(error)) => Some((error_type, error)),
+        (None, Some(error)) => Some((infer_error_type(&error)?, error)),
+        (Some(error_type), None) => {
+            return Err(Error::new_spanned(
+                error_type,
+                "`error_type` requires `error` or `error_fn` to be provided",
+            ))
+        }
+        (None, None) => None,
+    };
+    let error = match (error, &fallback) {
+        (Some((error_type, _)), Some((kind, _))) => {
+            return Err(Error::new_spanned(
+                error_type,
+                format!(
+                    "the error can't be provided together with a `#[try_from({kind})]` variant"
+                ),
+            ))
+        }
+        (Some((error_type, error)), None) if error_fn => quote!({ #error_type, @fn #error }),
+        (Some((error_type, error)), None) => quote!({ #error_type, #error }),
+        // Either the fallback variant makes the conversion infallible, or
+        // `TryFromReprError` is returned.
+        (None, _) => quote!({}),
+    };
+    let endian = options.endian;
+    let strategy = options.strategy;
+    let from_str = options.from_str;
+    let flag = |enabled: bool, name: &str| {
+        Ident::new(if enabled { name } else { "no" }, Span::call_site())
+    };
+    let display = flag(options.display, "display");
+    let debug = flag(options.debug, "debug");
+    let hex = flag(options.hex, "hex");
+    let serde = options.serde;
+    let bytemuck = options.bytemuck;
+    let consts = flag(options.consts, "consts");
+    let hook = options.deprecated_alias_hook;
+    let vis = &input.vis;
+    let name = &input.ident;
+    let primitive = primitive.into_iter();
+    let default_impl = if options.impl_default {
+        quote!(impl)
+    } else {
+        quote!(no)
+    };
+    let fallback = fallback.map(|(kind, ident)| quote!(#kind #ident));
+
+    Ok(quote! {
+        ::enum_try_from::__impl_enum_try_from! {
+            @impl [#endian #strategy [#from_str] [#display #debug #hex] #serde #bytemuck #consts #hook] #vis #name [#(#primitive)*] { #(#discriminants,)* } { #(#units,)* }
+            { #(#unit_ranges,)* } { #(#tuple_ranges,)* } [#fallback], #repr [], #error
+        }
+        ::enum_try_from::__impl_enum_try_from! {
+            @default_impl [#default_impl] #name [#fallback]
+        }
+    })
+}
@@ -0,0 +1,370 @@
+use enum_try_from::{Endian, TryFromReprError};
+use enum_try_from_derive::TryFromRepr;
+
+#[derive(Debug, PartialEq, Eq)]
+enum TestError {
+    InvalidValue,
+    UnknownValue(u16),
+    UnknownValueRaw(u16, u16),
+}
+
+#[test]
+fn test_derive_try_from_repr() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(repr = u16)]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+    assert_eq!(Test::try_from(0x5678), Ok(Test::Test2));
+    assert_eq!(
+        Test::try_from(0x9abc),
+        Err(TryFromReprError {
+            value: 0x9abc,
+            enum_name: "Test",
+            valid_values: &[0x1234, 0x5678],
+            valid_ranges: &[],
+        })
+    );
+}
+
+#[test]
+fn test_derive_try_from_repr_error() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(repr = u8, error = TestError::InvalidValue)]
+    #[repr(u8)]
+    enum Test {
+        Test = 1,
+        Test2 = 2,
+    }
+
+    assert_eq!(Test::try_from(1), Ok(Test::Test));
+    assert_eq!(Test::try_from(3), Err(TestError::InvalidValue));
+}
+
+#[test]
+fn test_derive_try_from_repr_be() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(repr = u16, endian = "be")]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(Test::try_from(0x1234_u16.to_be()), Ok(Test::Test));
+    assert_eq!(Test::try_from(0x5678_u16.to_be()), Ok(Test::Test2));
+
+    assert_eq!(u16::from(Test::Test), 0x1234_u16.to_be());
+}
+
+#[test]
+fn test_derive_try_from_repr_le() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(repr = u16, endian = "le")]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(Test::try_from(0x1234_u16.to_le()), Ok(Test::Test));
+    assert_eq!(Test::try_from(0x5678_u16.to_le()), Ok(Test::Test2));
+}
+
+#[test]
+fn test_derive_try_from_repr_runtime() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(repr = u16, endian = "runtime")]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(
+        Test::try_from_endian(0x1234_u16.to_be(), Endian::Big),
+        Ok(Test::Test)
+    );
+    assert_eq!(
+        Test::try_from_endian(0x5678_u16.to_le(), Endian::Little),
+        Ok(Test::Test2)
+    );
+}
+
+#[test]
+fn test_derive_try_from_repr_infer_repr() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(error = TestError::InvalidValue)]
+    #[repr(i8)]
+    enum Test {
+        Test = -1,
+        Test2 = 1,
+    }
+
+    assert_eq!(Test::try_from(-1), Ok(Test::Test));
+    assert_eq!(Test::try_from(1), Ok(Test::Test2));
+    assert_eq!(Test::try_from(0), Err(TestError::InvalidValue));
+}
+
+#[test]
+fn test_derive_try_from_repr_error_closure() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(error = |raw, v| TestError::UnknownValueRaw(raw, v), endian = "be")]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(
+        Test::try_from(0x9abc_u16.to_be()),
+        Err(TestError::UnknownValueRaw(0x9abc_u16.to_be(), 0x9abc))
+    );
+}
+
+#[test]
+fn test_derive_try_from_repr_error_fn() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(error_fn = TestError::UnknownValue)]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+    assert_eq!(Test::try_from(0x9abc), Err(TestError::UnknownValue(0x9abc)));
+}
+
+#[test]
+fn test_derive_try_from_repr_other() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[repr(u16)]
+    enum Test {
+        Foo = 0x1234,
+        Bar = 0x5678,
+        #[try_from(other)]
+        Unknown(u16),
+    }
+
+    assert_eq!(Test::from(0x1234), Test::Foo);
+    assert_eq!(Test::from(0x9abc), Test::Unknown(0x9abc));
+    assert_eq!(u16::from(Test::Unknown(0x9abc)), 0x9abc);
+}
+
+#[test]
+fn test_derive_try_from_repr_default() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(impl_default)]
+    #[repr(u8)]
+    enum Test {
+        #[try_from(default)]
+        Unspecified,
+        Foo,
+        Bar,
+    }
+
+    assert_eq!(Test::from(1), Test::Foo);
+    assert_eq!(Test::from(2), Test::Bar);
+    assert_eq!(Test::from(3), Test::Unspecified);
+    assert_eq!(Test::default(), Test::Unspecified);
+}
+
+#[test]
+fn test_derive_try_from_repr_range() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[repr(u8)]
+    enum Test {
+        Foo = 0x01,
+        #[try_from(range = 0x10..=0x1f)]
+        Reserved = 0x10,
+        #[try_from(range = 0x80..=0xff)]
+        Vendor(u8),
+    }
+
+    assert_eq!(Test::try_from(0x01), Ok(Test::Foo));
+    assert_eq!(Test::try_from(0x18), Ok(Test::Reserved));
+    assert_eq!(Test::try_from(0x9a), Ok(Test::Vendor(0x9a)));
+    assert_eq!(Test::try_from(0x20).unwrap_err().value, 0x20);
+    assert_eq!(u8::from(Test::Reserved), 0x10);
+    assert_eq!(u8::from(Test::Vendor(0x9a)), 0x9a);
+}
+
+#[test]
+fn test_derive_try_from_repr_alias() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DEPRECATED: AtomicUsize = AtomicUsize::new(0);
+
+    fn on_deprecated_alias(_v: u16, _variant: &Test) {
+        DEPRECATED.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(endian = "be", deprecated_alias_hook = on_deprecated_alias)]
+    #[repr(u16)]
+    enum Test {
+        #[try_from(alias = 0x9abc, deprecated_alias = 0xdef0)]
+        Foo = 0x1234,
+        Bar = 0x5678,
+    }
+
+    assert_eq!(Test::try_from(0x9abc_u16.to_be()), Ok(Test::Foo));
+    assert_eq!(DEPRECATED.load(Ordering::Relaxed), 0);
+    assert_eq!(Test::try_from(0xdef0_u16.to_be()), Ok(Test::Foo));
+    assert_eq!(DEPRECATED.load(Ordering::Relaxed), 1);
+    assert_eq!(u16::from(Test::Foo), 0x1234_u16.to_be());
+}
+
+#[test]
+fn test_derive_try_from_repr_strategy() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(strategy = "binary_search")]
+    #[repr(u16)]
+    enum Test {
+        Foo = 0x5678,
+        Bar = 0x1234,
+        #[try_from(other)]
+        Unknown(u16),
+    }
+
+    assert_eq!(Test::from(0x1234), Test::Bar);
+    assert_eq!(Test::from(0x5678), Test::Foo);
+    assert_eq!(Test::from(0x9abc), Test::Unknown(0x9abc));
+}
+
+#[test]
+fn test_derive_try_from_repr_consts() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(consts)]
+    #[repr(u16)]
+    pub enum Test {
+        Foo = 0x5678,
+        Bar = 0x1234,
+    }
+
+    assert_eq!(Test::ALL, [Test::Bar, Test::Foo]);
+    assert_eq!(Test::VALUES, [0x1234, 0x5678]);
+    assert_eq!(Test::COUNT, 2);
+    assert_eq!((Test::MIN, Test::MAX), (0x1234, 0x5678));
+}
+
+#[test]
+fn test_derive_try_from_repr_consts_range() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(consts)]
+    #[repr(u8)]
+    enum Test {
+        Foo = 0x05,
+        #[try_from(range = 0x10..=0x1f)]
+        Reserved,
+        Bar = 0x01,
+    }
+
+    enum_try_from::impl_enum_try_from!(
+        #[try_from(consts)]
+        #[repr(u8)]
+        #[derive(PartialEq, Eq, Debug)]
+        enum Macro {
+            Foo = 0x05,
+            Reserved = 0x10..=0x1f,
+            Bar = 0x01,
+        }
+    );
+
+    assert_eq!(Test::ALL, [Test::Bar, Test::Foo, Test::Reserved]);
+    assert_eq!(Macro::ALL, [Macro::Bar, Macro::Foo, Macro::Reserved]);
+    assert_eq!(Test::VALUES, Macro::VALUES);
+    assert_eq!(Test::VALUES, [0x01, 0x05, 0x10]);
+    assert_eq!((Test::MIN, Test::MAX), (Macro::MIN, Macro::MAX));
+}
+
+#[test]
+fn test_derive_try_from_repr_from_str() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(from_str = "snake_case")]
+    #[repr(u8)]
+    enum Test {
+        #[try_from(rename = "foo", name_alias = "legacy_foo")]
+        FooBar = 0x01,
+        BarBaz = 0x02,
+    }
+
+    assert_eq!("foo".parse(), Ok(Test::FooBar));
+    assert_eq!("legacy_foo".parse(), Ok(Test::FooBar));
+    assert_eq!(Test::try_from("bar_baz"), Ok(Test::BarBaz));
+    assert!(Test::try_from("foo_bar").is_err());
+}
+
+#[test]
+fn test_derive_try_from_repr_fmt() {
+    #[derive(TryFromRepr, PartialEq, Eq)]
+    #[try_from(display, debug, hex)]
+    #[repr(u16)]
+    enum Test {
+        #[try_from(rename = "ETH_P_IP")]
+        Ipv4 = 0x0800,
+        Ipv6 = 0x86dd,
+    }
+
+    assert_eq!(format!("{}", Test::Ipv4), "ETH_P_IP");
+    assert_eq!(format!("{:?}", Test::Ipv6), "Ipv6(0x86dd)");
+    assert_eq!(format!("{:#06x}", Test::Ipv4), "0x0800");
+}
+
+#[test]
+fn test_derive_try_from_repr_serde() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(serde = "name", from_str = "snake_case")]
+    #[repr(u16)]
+    enum Test {
+        FooBar = 0x1234,
+        Baz = 0x5678,
+    }
+
+    assert_tokens(&Test::FooBar, &[Token::Str("foo_bar")]);
+    assert_de_tokens_error::<Test>(
+        &[Token::Str("qux")],
+        "unknown variant `qux`, expected `foo_bar` or `baz`",
+    );
+}
+
+#[test]
+fn test_derive_try_from_repr_bytes() {
+    #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+    #[try_from(endian = "be")]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(Test::try_from([0x12, 0x34]), Ok(Test::Test));
+    assert_eq!(Test::try_from_le_bytes([0x78, 0x56]), Ok(Test::Test2));
+    assert_eq!(
+        Test::try_read_from(&[0x56, 0x78, 0x9a]),
+        Ok((Test::Test2, &[0x9a][..]))
+    );
+}
+
+#[test]
+fn test_derive_try_from_repr_bytemuck() {
+    use bytemuck::checked;
+
+    #[derive(TryFromRepr, Clone, Copy, PartialEq, Eq, Debug)]
+    #[try_from(bytemuck)]
+    #[repr(u16)]
+    enum Test {
+        Test = 0x1234,
+        Test2 = 0x5678,
+    }
+
+    assert_eq!(checked::try_cast::<u16, Test>(0x5678), Ok(Test::Test2));
+    assert!(checked::try_cast::<u16, Test>(0x9abc).is_err());
+}
@@ -76,6 +76,73 @@
 //! }
 //! ```
 //!
+//! If the value provided to `try_from` should be converted from little endian:
+//!
+//! ```rust
+//! use enum_try_from::impl_enum_try_from_le;
+//!
+//! impl_enum_try_from_le!(
+//!    #[repr(u16)]
+//!    #[derive(PartialEq, Eq, Debug)]
+//!    enum MyEnum {
+//!       Foo = 0x1234,
+//!       Bar = 0x5678,
+//!       Baz = 0x9abc,
+//!    },
+//!    u16,
+//!    (),
+//!    ()
+//! );
+//!
+//! fn main() {
+//!     assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0x34, 0x12])), Ok(MyEnum::Foo));
+//!     assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0x78, 0x56])), Ok(MyEnum::Bar));
+//!     assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0xbc, 0x9a])), Ok(MyEnum::Baz));
+//!     assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0xf0, 0xde])), Err(()));
+//! }
+//! ```
+//!
+//! With the `derive` feature enabled, the same implementation can be generated
+//! with the `TryFromRepr` derive macro, without wrapping the enum definition in
+//! a macro call:
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # mod example {
+//! use enum_try_from::TryFromRepr;
+//!
+//! #[derive(TryFromRepr, PartialEq, Eq, Debug)]
+//! #[try_from(repr = u16, endian = "be")]
+//! #[repr(u16)]
+//! enum MyEnum {
+//!     Foo = 0x1234,
+//!     Bar = 0x5678,
+//!     Baz = 0x9abc,
+//! }
+//! # }
+//! ```
+//!
+//! With the `serde` feature enabled, the `#[try_from(serde)]` option implements
+//! `Serialize` and `Deserialize`, with the values validated by `try_from`:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # mod example {
+//! use enum_try_from::impl_enum_try_from;
+//!
+//! impl_enum_try_from!(
+//!     #[try_from(serde)]
+//!     #[repr(u16)]
+//!     #[derive(PartialEq, Eq, Debug)]
+//!     enum MyEnum {
+//!         Foo = 0x1234,
+//!         Bar = 0x5678,
+//!         Baz = 0x9abc,
+//!     }
+//! );
+//! # }
+//! ```
+//!
 //! ## Why does it exist?
 //!
 //! Rust projects very often consume values as regular integers and then try to
@@ -120,14 +187,485 @@
 
 #![no_std]
 
+use core::fmt;
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "derive")]
+pub use enum_try_from_derive::TryFromRepr;
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+pub use zerocopy as __zerocopy;
+
+/// Byte order of the value provided to the generated conversions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Native byte order of the target.
+    Native,
+    /// Big endian.
+    Big,
+    /// Little endian.
+    Little,
+}
+
+/// Error returned by the generated `try_from` when no error is provided to the
+/// macro. It holds the value which doesn't match any variant of the enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TryFromReprError<T: 'static> {
+    /// The value provided to `try_from`, in native byte order.
+    pub value: T,
+    /// Name of the enum.
+    pub enum_name: &'static str,
+    /// Discriminants of the unit variants, followed by their aliases.
+    pub valid_values: &'static [T],
+    /// Ranges of values of the variants with ranges.
+    pub valid_ranges: &'static [RangeInclusive<T>],
+}
+
+impl<T: fmt::Display> fmt::Display for TryFromReprError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value {} for `{}`", self.value, self.enum_name)?;
+        let mut sep = ", expected one of: ";
+        for value in self.valid_values {
+            write!(f, "{sep}{value}")?;
+            sep = ", ";
+        }
+        for range in self.valid_ranges {
+            write!(f, "{sep}{}..={}", range.start(), range.end())?;
+            sep = ", ";
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for TryFromReprError<T> {}
+
+/// Error returned by the generated `try_read_from`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TryReadError<E> {
+    /// The input is shorter than the value.
+    Truncated {
+        /// Number of bytes of the value.
+        needed: usize,
+        /// Number of bytes in the input.
+        available: usize,
+    },
+    /// The value doesn't match any variant. Holds the error of `try_from`.
+    Invalid(E),
+}
+
+impl<E: fmt::Display> fmt::Display for TryReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReadError::Truncated { needed, available } => {
+                write!(
+                    f,
+                    "truncated input, expected {needed} bytes, got {available}"
+                )
+            }
+            TryReadError::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for TryReadError<E> {}
+
+/// Error returned by the `try_from` generated by [`impl_flags_try_from`] when
+/// the value has bits which don't match any flag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TryFromFlagsError<T> {
+    /// The value provided to `try_from`, in native byte order.
+    pub value: T,
+    /// The bits of the value which don't match any flag.
+    pub unknown_bits: T,
+    /// Name of the flag set.
+    pub flags_name: &'static str,
+}
+
+impl<T: fmt::LowerHex> fmt::Display for TryFromFlagsError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid bits {:#x} in value {:#x} for `{}`",
+            self.unknown_bits, self.value, self.flags_name
+        )
+    }
+}
+
+impl<T: fmt::Debug + fmt::LowerHex> core::error::Error for TryFromFlagsError<T> {}
+
+/// Error returned by the `from_str` and `try_from` generated with the
+/// `from_str` option when the string doesn't match the name of any variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseNameError {
+    /// Name of the enum.
+    pub enum_name: &'static str,
+}
+
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid name for `{}`", self.enum_name)
+    }
+}
+
+impl core::error::Error for ParseNameError {}
+
+/// Conversions of the enums generated by the macros of this crate from and to
+/// the type they're converted from. If there are more types, it's the first
+/// one.
+pub trait EnumRepr: Sized {
+    /// The type the enum is converted from.
+    type Repr: Copy;
+
+    /// Returns the variant matching the value, given in the byte order of
+    /// `try_from`, or `None` if it doesn't match any variant. The fallback
+    /// variant is not returned for the values which don't match the others.
+    /// Same as the inherent `from_repr` const function of the enum, but it
+    /// calls the `deprecated_alias_hook`.
+    fn from_repr(v: Self::Repr) -> Option<Self>;
+
+    /// Converts the variant to the value, in the byte order of `try_from`, the
+    /// same as the inherent `repr` const function of the enum.
+    fn to_repr(&self) -> Self::Repr;
+}
+
+/// Source of the fields of the variants of an enum with the `tag_enum` option.
+/// The generated `try_from_tag_and` reads the fields of the variant matching
+/// the tag with it, in the order they're declared in, so it's implemented for
+/// the type of each field.
+pub trait ReadField<T> {
+    /// Error returned if the field can't be read.
+    type Error;
+
+    /// Reads the next field.
+    fn read_field(&mut self) -> Result<T, Self::Error>;
+}
+
+/// A variant of the enum `E` or a value which doesn't match any variant.
+///
+/// The conversion from the type of the enum can't fail, and the unknown values
+/// are preserved, so they're converted back to the same value. The known ones
+/// are converted back to the value of their variant, the same as the enum
+/// itself, which loses the original value of an alias or of a value inside a
+/// range other than its start. The values are compared, ordered and hashed by
+/// the values they're converted to.
+///
+/// ```
+/// use enum_try_from::{impl_enum_try_from_be, MaybeKnown};
+///
+/// impl_enum_try_from_be!(
+///     #[repr(u16)]
+///     #[derive(Debug)]
+///     enum EtherType {
+///         Ipv4 = 0x0800,
+///         Ipv6 = 0x86dd,
+///     }
+/// );
+///
+/// let ether_type = MaybeKnown::<EtherType>::from(0x0800_u16.to_be());
+/// assert!(matches!(ether_type, MaybeKnown::Known(EtherType::Ipv4)));
+///
+/// let ether_type = MaybeKnown::<EtherType>::from(0x88cc_u16.to_be());
+/// assert!(!ether_type.is_known());
+/// assert_eq!(u16::from(ether_type), 0x88cc_u16.to_be());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub enum MaybeKnown<E: EnumRepr> {
+    /// A value matching a variant.
+    Known(E),
+    /// A value which doesn't match any variant, in the byte order of
+    /// `try_from`.
+    Unknown(E::Repr),
+}
+
+impl<E: EnumRepr> MaybeKnown<E> {
+    /// Converts the value, given in the byte order of `try_from`.
+    pub fn from_repr(v: E::Repr) -> Self {
+        match E::from_repr(v) {
+            Some(e) => MaybeKnown::Known(e),
+            None => MaybeKnown::Unknown(v),
+        }
+    }
+
+    /// Converts back to the value, in the byte order of `try_from`.
+    pub fn to_repr(&self) -> E::Repr {
+        match self {
+            MaybeKnown::Known(e) => e.to_repr(),
+            MaybeKnown::Unknown(v) => *v,
+        }
+    }
+
+    /// Returns `true` if the value matches a variant.
+    pub fn is_known(&self) -> bool {
+        matches!(self, MaybeKnown::Known(_))
+    }
+
+    /// Returns the variant, or `None` if the value doesn't match any.
+    pub fn known(self) -> Option<E> {
+        match self {
+            MaybeKnown::Known(e) => Some(e),
+            MaybeKnown::Unknown(_) => None,
+        }
+    }
+}
+
+impl<E: EnumRepr> From<E> for MaybeKnown<E> {
+    fn from(e: E) -> Self {
+        MaybeKnown::Known(e)
+    }
+}
+
+impl<E: EnumRepr> PartialEq for MaybeKnown<E>
+where
+    E::Repr: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.to_repr() == other.to_repr()
+    }
+}
+
+impl<E: EnumRepr> Eq for MaybeKnown<E> where E::Repr: Eq {}
+
+impl<E: EnumRepr> PartialOrd for MaybeKnown<E>
+where
+    E::Repr: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: EnumRepr> Ord for MaybeKnown<E>
+where
+    E::Repr: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_repr().cmp(&other.to_repr())
+    }
+}
+
+impl<E: EnumRepr> core::hash::Hash for MaybeKnown<E>
+where
+    E::Repr: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.to_repr().hash(state)
+    }
+}
+
+// `From<E::Repr>` would conflict with `From<T> for T`, so the conversions are
+// implemented for each integer type.
+macro_rules! impl_maybe_known_from {
+    ($($int:ty),*) => {
+        $(
+            impl<E: EnumRepr<Repr = $int>> From<$int> for MaybeKnown<E> {
+                fn from(v: $int) -> Self {
+                    MaybeKnown::from_repr(v)
+                }
+            }
+
+            impl<E: EnumRepr<Repr = $int>> From<MaybeKnown<E>> for $int {
+                fn from(v: MaybeKnown<E>) -> Self {
+                    v.to_repr()
+                }
+            }
+        )*
+    };
+}
+
+impl_maybe_known_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Case of the names accepted by the generated `from_str`.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub enum __NameCase {
+    Exact,
+    CaseInsensitive,
+    SnakeCase,
+    KebabCase,
+}
+
+impl __NameCase {
+    /// Case of the names which are provided as strings, which are not
+    /// converted to snake or kebab case.
+    pub const fn literal(self) -> Self {
+        match self {
+            __NameCase::CaseInsensitive => __NameCase::CaseInsensitive,
+            _ => __NameCase::Exact,
+        }
+    }
+}
+
+/// Bytes of a name converted to a case, without allocating the converted name.
+struct NameBytes<'a> {
+    name: &'a [u8],
+    case: __NameCase,
+    i: usize,
+    separated: bool,
+}
+
+impl<'a> NameBytes<'a> {
+    const fn new(name: &'a str, case: __NameCase) -> Self {
+        NameBytes {
+            name: name.as_bytes(),
+            case,
+            i: 0,
+            separated: false,
+        }
+    }
+
+    const fn next(&mut self) -> Option<u8> {
+        if self.i >= self.name.len() {
+            return None;
+        }
+        let c = self.name[self.i];
+        let separator = match self.case {
+            __NameCase::Exact => {
+                self.i += 1;
+                return Some(c);
+            }
+            __NameCase::CaseInsensitive => {
+                self.i += 1;
+                return Some(c.to_ascii_lowercase());
+            }
+            __NameCase::SnakeCase => b'_',
+            __NameCase::KebabCase => b'-',
+        };
+        if c == b'_' {
+            self.i += 1;
+            return Some(separator);
+        }
+        // Start a new word with an uppercase letter following a lowercase
+        // letter or a digit, or starting a lowercase word after an acronym.
+        if c.is_ascii_uppercase() && self.i > 0 && !self.separated {
+            let prev = self.name[self.i - 1];
+            let next_lowercase =
+                self.i + 1 < self.name.len() && self.name[self.i + 1].is_ascii_lowercase();
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lowercase)
+            {
+                self.separated = true;
+                return Some(separator);
+            }
+        }
+        self.separated = false;
+        self.i += 1;
+        Some(c.to_ascii_lowercase())
+    }
+}
+
+/// Length of the name converted to the case.
+#[doc(hidden)]
+pub const fn __name_len(name: &str, case: __NameCase) -> usize {
+    let mut bytes = NameBytes::new(name, case);
+    let mut len = 0;
+    while bytes.next().is_some() {
+        len += 1;
+    }
+    len
+}
+
+/// The name converted to the case, with `N` being its length.
+#[doc(hidden)]
+pub const fn __name_bytes<const N: usize>(name: &str, case: __NameCase) -> [u8; N] {
+    let mut bytes = NameBytes::new(name, case);
+    let mut converted = [0; N];
+    let mut i = 0;
+    while let Some(b) = bytes.next() {
+        converted[i] = b;
+        i += 1;
+    }
+    converted
+}
+
+/// Checks whether the names are equal after converting them to their cases.
+#[doc(hidden)]
+pub const fn __name_eq(a: &str, a_case: __NameCase, b: &str, b_case: __NameCase) -> bool {
+    let mut a = NameBytes::new(a, a_case);
+    let mut b = NameBytes::new(b, b_case);
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return true,
+            (Some(a), Some(b)) if a == b => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Counts the names which are equal to the given name. Used by the generated
+/// code to reject names matching multiple variants at compile time.
+#[doc(hidden)]
+pub const fn __count_names(names: &[(&str, __NameCase)], name: &str, case: __NameCase) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < names.len() {
+        if __name_eq(names[i].0, names[i].1, name, case) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Counts the ranges which overlap with the given range. Used by the generated
+/// code to reject overlapping variants at compile time.
+#[doc(hidden)]
+pub const fn __count_overlapping(ranges: &[(i128, i128)], range: (i128, i128)) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < ranges.len() {
+        if ranges[i].0 <= range.1 && range.0 <= ranges[i].1 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
 /// Macro which implements the `TryFrom` trait for the given enum and type.
 ///
 /// The first argument is the enum to implement the trait for.
 ///
-/// The second argument is the type to implement the trait for. Usually `i32` or
-/// `u32` would be the best choice. However, if you are providing any concrete
-/// primitive type in `repr` (i.e. `#[repr(u8)]`), then you should use the same
-/// type.
+/// The second argument is the type to implement the trait for. It's optional
+/// if the enum has a `repr` attribute with a primitive integer type (i.e.
+/// `#[repr(u8)]`), which is then used. Otherwise, usually `i32` or `u32` would
+/// be the best choice.
+///
+/// The type can also be a list of types, i.e. `[u8, u16, i32]`, to implement
+/// `TryFrom` for each of them. The first type is used for the other
+/// conversions, such as `From<MyEnum>`, and the values of the other types are
+/// converted to it with `TryFrom` before matching the variants, so a value
+/// which doesn't fit in it is rejected instead of being truncated. The byte
+/// order of the `endian` option applies to each type separately. An error
+/// closure or function is called with the value of the type converted from,
+/// and [`TryFromReprError`] lists only the values which fit in it, with the
+/// ranges cut to it. A `#[try_from(other)]` variant can only hold values of a
+/// single type, so it can't be used with a list of types.
+///
+/// ```
+/// use enum_try_from::impl_enum_try_from;
+///
+/// impl_enum_try_from!(
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum Signal {
+///         Hup = 1,
+///         Int = 2,
+///     },
+///     [u8, i32, u64],
+/// );
+///
+/// assert_eq!(Signal::try_from(2_i32), Ok(Signal::Int));
+/// assert!(Signal::try_from(-255_i32).is_err());
+/// assert!(Signal::try_from(0x101_u64).is_err());
+/// ```
 ///
 /// The third argument is the type of the error which should be returned if the
 /// value provided to `try_from` is not a valid variant of the enum.
@@ -135,6 +673,198 @@
 /// The fourth argument is the concrete error value which should be returned if
 /// the value provided to `try_from` is not a valid variant of the enum.
 ///
+/// Instead of a concrete value, the fourth argument can be a closure which is
+/// called with the value provided to `try_from` to create the error. A closure
+/// with two arguments is called with both the value provided to `try_from` and
+/// the value converted to native byte order (which is the same value unless the
+/// `endian` option is used). The parameters of the closure can have types, i.e.
+/// `|v: u16|`, if they're identifiers. A path to a function, i.e. a tuple
+/// variant of the error, is called with the value provided to `try_from` if the
+/// `#[try_from(error_fn)]` option is used.
+///
+/// The error arguments are optional. If they are omitted, [`TryFromReprError`]
+/// holding the invalid value is returned.
+///
+/// The reverse conversion is implemented as well, with `From<MyEnum>` and
+/// `From<&MyEnum>` for the type. It applies the same byte order as `try_from`,
+/// so converting the enum back gives the original value.
+///
+/// Since `try_from` can't be called in const contexts, the enum also gets the
+/// `from_repr` and `repr` const functions, i.e. to build static tables. They
+/// convert from and to a value in the byte order of `try_from`, the same as
+/// [`EnumRepr`]. `from_ne_repr` and `ne_repr` do the same with a value in
+/// native byte order. With the `endian = "be"` option, `from_be_repr` and
+/// `be_repr` are also defined for big endian values, and with
+/// `endian = "le"`, `from_le_repr` and `le_repr` for little endian values.
+/// `try_from` delegates to them, but unlike `try_from`, they don't call the
+/// `deprecated_alias_hook`.
+///
+/// The [`const_try_from`] macro converts constants with `from_repr`, failing
+/// the compilation if they don't match any variant.
+///
+/// ```
+/// use enum_try_from::impl_enum_try_from_be;
+///
+/// impl_enum_try_from_be!(
+///     #[repr(u16)]
+///     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///     enum EtherType {
+///         Ipv4 = 0x0800,
+///         Ipv6 = 0x86dd,
+///     }
+/// );
+///
+/// static ETHER_TYPES: [Option<EtherType>; 2] = [
+///     EtherType::from_repr(0x0800_u16.to_be()),
+///     EtherType::from_ne_repr(0x86dd),
+/// ];
+/// const IPV6: u16 = EtherType::Ipv6.ne_repr();
+///
+/// assert_eq!(ETHER_TYPES, [Some(EtherType::Ipv4), Some(EtherType::Ipv6)]);
+/// assert_eq!(IPV6, 0x86dd);
+/// assert_eq!(EtherType::Ipv6.repr(), 0x86dd_u16.to_be());
+/// assert_eq!(EtherType::Ipv6.be_repr(), 0x86dd_u16.to_be());
+/// ```
+///
+/// Every discriminant of the enum has to be exactly representable in the type,
+/// otherwise the compilation fails. This prevents discriminants from being
+/// truncated, changing the sign or colliding with each other after the cast.
+///
+/// A tuple variant with a single field of the type can be marked with
+/// `#[try_from(other)]` to hold the values which don't match any other variant.
+/// The conversion can't fail then, so `From` is implemented instead of
+/// `TryFrom` and the error arguments must be omitted. The reverse conversion
+/// gives back the held value, so unknown values are preserved.
+///
+/// Similarly, a unit variant can be marked with `#[try_from(default)]` to be
+/// returned for the values which don't match any other variant, also
+/// implementing `From` instead of `TryFrom`.
+///
+/// Instead of a discriminant, a variant can be given an inclusive range of
+/// values, i.e. `Reserved = 0x10..=0x1f`. The bounds have to be literals or
+/// constants. A unit variant matches every value in the range and gets the
+/// start of the range as its discriminant, which it's also converted back to.
+/// A tuple variant with a single field of the type, i.e.
+/// `Vendor(u16) = 0x8000..=0xffff`, holds the matched value, which it's
+/// converted back to. Ranges overlapping with each other or with other variants
+/// are rejected at compile time.
+///
+/// A unit variant can accept additional values with the
+/// `#[try_from(alias = 0x05, alias = 0x85)]` attribute. The reverse conversion
+/// always gives the discriminant. Values accepted only for backwards
+/// compatibility can be marked with `#[try_from(deprecated_alias = 0x03)]`,
+/// which are accepted the same way, but also passed to the function from the
+/// `deprecated_alias_hook` option, i.e. to log or count them.
+///
+/// With the `from_str` option, a unit variant can be converted from another
+/// name with `#[try_from(rename = "foo")]`, and additionally from the names in
+/// `#[try_from(name_alias = "legacy_foo")]`. These names are not converted to
+/// snake or kebab case. Names matching more than one variant are rejected at
+/// compile time. [`ParseNameError`] is returned for unknown names. The name
+/// from `rename` is also the one printed by the `display` and `debug` options.
+///
+/// With the `consts` option, the enum gets the `ALL`, `VALUES`, `COUNT`, `MIN`
+/// and `MAX` associated constants, with the same visibility as the enum. `ALL`
+/// contains the unit variants sorted by their discriminants, `VALUES` the
+/// sorted discriminants in native byte order, `COUNT` the number of the unit
+/// variants, and `MIN` and `MAX` the lowest and the highest discriminant. A
+/// unit variant with a range is included with the start of the range.
+///
+/// The enum implements [`EnumRepr`], so it can be wrapped in [`MaybeKnown`]
+/// to keep the values which don't match any variant.
+///
+/// With the `tag_enum = Name` option, the variants can have fields and each of
+/// them is marked with a `#[tag = 0x01]` attribute instead of a discriminant.
+/// The fieldless `Name` enum is then defined with variants of the same names,
+/// the tags as discriminants, the `#[try_from(...)]` options of the variants
+/// and all the conversions described here. The enum itself gets a `tag` method
+/// returning the variant of `Name`, and a `try_from_tag_and` function which
+/// converts a value to `Name` with `try_from` and builds the variant with that
+/// tag, reading its fields in order from a reader implementing [`ReadField`]
+/// for the type of each field. The error of the reader has to implement `From`
+/// for the error of `try_from`.
+///
+/// The enum can also be converted from the bytes of the value.
+/// `TryFrom<[u8; N]>` takes the bytes in the byte order of `try_from` (it's
+/// `From<[u8; N]>` if the enum has a fallback variant), while
+/// `try_from_be_bytes`, `try_from_le_bytes` and `try_from_ne_bytes` take them
+/// in the byte order of their names. `try_read_from` converts the first bytes
+/// of a slice the same way as `TryFrom<[u8; N]>` and returns the enum with the
+/// remaining bytes, or [`TryReadError`] if the slice is too short or the value
+/// is invalid.
+///
+/// The enum can be preceded by a `#[try_from(...)]` attribute with options of
+/// the generated code. The attribute is not applied to the enum itself. The
+/// available options are:
+///
+/// * `endian = "ne"` - the value provided to `try_from` is in native byte order
+///   (default).
+/// * `endian = "be"` - the value provided to `try_from` is converted from big
+///   endian, same as with [`impl_enum_try_from_be`].
+/// * `endian = "le"` - the value provided to `try_from` is converted from little
+///   endian, same as with [`impl_enum_try_from_le`].
+/// * `endian = "runtime"` - the value provided to `try_from` is in native byte
+///   order, and additional `try_from_endian` and `to_endian` methods take the
+///   byte order as an [`Endian`] argument.
+/// * `error_fn` - the fourth argument is a function which is called with the
+///   value provided to `try_from` to create the error.
+/// * `impl_default` - `Default` is implemented, returning the variant marked
+///   with `#[try_from(default)]`.
+/// * `strategy = "auto"` - the discriminants are looked up with `"table"` if the
+///   table has at most twice as many entries as there are variants, and with
+///   `"match"` otherwise (default).
+/// * `strategy = "match"` - the discriminants are matched as constant patterns,
+///   which the compiler turns into a jump table or a decision tree.
+/// * `strategy = "table"` - the discriminants are looked up in a table with an
+///   entry for every value between the lowest and the highest discriminant.
+///   The table can have at most 65536 entries.
+/// * `strategy = "binary_search"` - the discriminants are looked up with a
+///   binary search in a sorted array.
+/// * `from_str` or `from_str = "exact"` - `FromStr` and `TryFrom<&str>` are
+///   implemented, converting the names of the unit variants.
+/// * `from_str = "case_insensitive"` - same as `from_str`, ignoring the ASCII
+///   case of the names.
+/// * `from_str = "snake_case"` and `from_str = "kebab_case"` - same as
+///   `from_str`, with the names converted to snake case (`BarBaz` to `bar_baz`)
+///   or kebab case (`BarBaz` to `bar-baz`).
+/// * `display` - `Display` is implemented, printing the names of the variants,
+///   converted to the case of `from_str`.
+/// * `debug` - `Debug` is implemented, printing the names and the values of the
+///   variants, padded to the width of the type, i.e. `Foo(0x0012)` for `u16`.
+///   It can't be combined with `#[derive(Debug)]`.
+/// * `hex` - `LowerHex` and `UpperHex` are implemented, formatting the values of
+///   the variants in native byte order.
+/// * `serde` or `serde = "value"` - `Serialize` and `Deserialize` are
+///   implemented with the values of the variants in native byte order. The
+///   values are converted with `try_from` and the invalid ones are rejected
+///   with an error containing the value. Requires the `serde` feature.
+/// * `serde = "name"` - same as `serde`, with the names of the variants in the
+///   case of `from_str`, which is required. It can't be used with tuple
+///   variants.
+/// * `serde = "lenient"` - same as `serde`, but human-readable formats accept
+///   both the names and the values, and the unit variants are serialized as
+///   names. Requires `from_str`.
+/// * `tag_enum = Name` - the variants can have fields and are converted with
+///   the `Name` enum of their tags.
+/// * `bytemuck` - `bytemuck::CheckedBitPattern` and `bytemuck::NoUninit` are
+///   implemented, with the discriminants of the variants as the only valid bit
+///   patterns. The enum has to be `Copy`, have a primitive `#[repr(...)]` and no
+///   tuple variants. Requires the `bytemuck` feature.
+/// * `bytemuck = "contiguous"` - same as `bytemuck`, also implementing
+///   `bytemuck::Contiguous`. The discriminants have to be contiguous.
+/// * `zerocopy` - `zerocopy::TryFromBytes`, `zerocopy::IntoBytes`,
+///   `zerocopy::KnownLayout` and `zerocopy::Immutable` are derived for the enum.
+///   Requires the `zerocopy` feature. The derives refer to `zerocopy` as
+///   `enum_try_from::__zerocopy`, which can't be resolved if `enum-try-from` is
+///   renamed in `Cargo.toml`.
+/// * `zerocopy = "etf::__zerocopy"` - same as `zerocopy`, with the path to the
+///   `__zerocopy` re-export of a renamed `enum-try-from` dependency.
+/// * `deprecated_alias_hook = path::to::function` - the function is called with
+///   the value in native byte order and a reference to the variant when a
+///   deprecated alias is converted to the enum.
+/// * `consts` - the `ALL`, `VALUES`, `COUNT`, `MIN` and `MAX` associated
+///   constants are defined.
+///
 /// # Examples
 ///
 /// ```
@@ -157,6 +887,9 @@
 /// assert_eq!(MyEnum::try_from(1), Ok(MyEnum::Bar));
 /// assert_eq!(MyEnum::try_from(2), Ok(MyEnum::Baz));
 /// assert_eq!(MyEnum::try_from(3), Err(()));
+///
+/// assert_eq!(u16::from(MyEnum::Foo), 0);
+/// assert_eq!(u16::from(&MyEnum::Baz), 2);
 /// # }
 /// ```
 ///
@@ -183,154 +916,5037 @@
 ///     MyError::InvalidValue,
 /// );
 /// ```
-#[macro_export]
-macro_rules! impl_enum_try_from {
-    ($(#[$meta:meta])* $vis:vis enum $name:ident {
-        $($(#[$vmeta:meta])* $vname:ident $(= $val:expr)?,)*
-    }, $type:ty, $err_ty:ty, $err:expr $(,)?) => {
-        $(#[$meta])*
-        $vis enum $name {
-            $($(#[$vmeta])* $vname $(= $val)?,)*
-        }
-
-        impl TryFrom<$type> for $name {
-            type Error = $err_ty;
-
-            fn try_from(v: $type) -> Result<Self, Self::Error> {
-                match v {
-                    $(x if x == $name::$vname as $type => Ok($name::$vname),)*
-                    _ => Err($err),
-                }
-            }
-        }
-    }
-}
-
-/// Macro which implements the `TryFrom` trait for the given enum and type, with
-/// conversion of the input value from big endian.
-///
-/// The first argument is the enum to implement the trait for.
-///
-/// The second argument is the type to implement the trait for. Usually `i32` or
-/// `u32` would be the best choice. However, if you are providing any concrete
-/// primitive type in `repr` (i.e. `#[repr(u8)]`), then you should use the same
-/// type.
-///
-/// The third argument is the type of the error which should be returned if the
-/// value provided to `try_from` is not a valid variant of the enum.
-///
-/// The fourth argument is the concrete error value which should be returned if
-/// the value provided to `try_from` is not a valid variant of the enum.
-///
-/// # Examples
 ///
 /// ```
-/// # use enum_try_from::impl_enum_try_from_be;
-/// impl_enum_try_from_be!(
-///    #[repr(u16)]
-///    #[derive(PartialEq, Eq, Debug)]
-///    enum MyEnum {
-///       Foo = 0x1234,
-///       Bar = 0x5678,
-///       Baz = 0x9abc,
-///    },
-///    u16,
-///    (),
-///    ()
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///        Foo = 0,
+///        Bar = 1,
+///     },
+///     (),
+///     ()
 /// );
 ///
 /// # fn main() {
-/// assert_eq!(MyEnum::try_from(0x3412), Ok(MyEnum::Foo));
-/// assert_eq!(MyEnum::try_from(0x7856), Ok(MyEnum::Bar));
-/// assert_eq!(MyEnum::try_from(0xbc9a), Ok(MyEnum::Baz));
-/// assert_eq!(MyEnum::try_from(0xdef0), Err(()));
+/// assert_eq!(MyEnum::try_from(0_u8), Ok(MyEnum::Foo));
+/// assert_eq!(MyEnum::try_from(1_u8), Ok(MyEnum::Bar));
+/// assert_eq!(MyEnum::try_from(2_u8), Err(()));
 /// # }
 /// ```
 ///
 /// ```
-/// use thiserror::Error;
-/// # use enum_try_from::impl_enum_try_from_be;
-///
-/// #[derive(Error, Debug, PartialEq, Eq)]
+/// # use enum_try_from::{impl_enum_try_from, TryFromReprError};
+/// impl_enum_try_from!(
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///        Foo = 0,
+///        Bar = 1,
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!(MyEnum::try_from(0), Ok(MyEnum::Foo));
+/// let err = MyEnum::try_from(2).unwrap_err();
+/// assert_eq!(err.value, 2);
+/// assert_eq!(err.enum_name, "MyEnum");
+/// assert_eq!(err.valid_values, &[0, 1]);
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid value 2 for `MyEnum`, expected one of: 0, 1",
+/// );
+/// # }
+/// ```
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from_be;
+/// #[derive(Debug, PartialEq, Eq)]
 /// pub enum MyError {
-///     #[error("invalid value")]
-///     InvalidValue,
+///     UnknownOpcode(u16),
+///     UnknownOpcodeRaw { raw: u16, value: u16 },
 /// }
 ///
 /// impl_enum_try_from_be!(
 ///     #[repr(u16)]
 ///     #[derive(PartialEq, Eq, Debug)]
 ///     enum MyEnum {
-///         Foo = 0x1234,
-///         Bar = 0x5678,
-///         Baz = 0x9abc,
+///        Foo = 0x1234,
+///        Bar = 0x5678,
 ///     },
-///     u16,
 ///     MyError,
-///     MyError::InvalidValue,
+///     |raw, value| MyError::UnknownOpcodeRaw { raw, value }
+/// );
+///
+/// impl_enum_try_from_be!(
+///     #[try_from(error_fn)]
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyOtherEnum {
+///        Foo = 0x1234,
+///        Bar = 0x5678,
+///     },
+///     MyError,
+///     MyError::UnknownOpcode
+/// );
+///
+/// # fn main() {
+/// let raw = u16::from_ne_bytes([0x9a, 0xbc]);
+/// assert_eq!(
+///     MyEnum::try_from(raw),
+///     Err(MyError::UnknownOpcodeRaw { raw, value: 0x9abc }),
 /// );
+/// assert_eq!(MyOtherEnum::try_from(raw), Err(MyError::UnknownOpcode(raw)));
+/// # }
 /// ```
-#[macro_export]
-macro_rules! impl_enum_try_from_be {
-    ($(#[$meta:meta])* $vis:vis enum $name:ident {
-        $($(#[$vmeta:meta])* $vname:ident $(= $val:expr)?,)*
-    }, $type:ty, $err_ty:ty, $err:expr $(,)?) => {
-        $(#[$meta])*
-        $vis enum $name {
-            $($(#[$vmeta])* $vname $(= $val)?,)*
+///
+/// Omitting the type without a `repr` attribute is an error:
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     enum MyEnum {
+///        Foo = 0,
+///        Bar = 1,
+///     },
+///     (),
+///     ()
+/// );
+/// ```
+///
+/// Discriminants which don't fit in the type are an error:
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(u16)]
+///     enum MyEnum {
+///        Foo = 0x1234,
+///        Bar = 0x5634,
+///     },
+///     u8,
+///     (),
+///     ()
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(i8)]
+///     enum MyEnum {
+///        Foo = -1,
+///        Bar = 1,
+///     },
+///     u8,
+///     (),
+///     ()
+/// );
+/// ```
+///
+/// The same applies to 128-bit types, where the bits don't change:
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(i128)]
+///     enum MyEnum {
+///        Foo = -1,
+///        Bar = 1,
+///     },
+///     u128,
+///     (),
+///     ()
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(u128)]
+///     enum MyEnum {
+///        Foo = u128::MAX,
+///        Bar = 1,
+///     },
+///     i128,
+///     (),
+///     ()
+/// );
+/// ```
+///
+/// ```
+/// # use enum_try_from::{impl_enum_try_from, Endian};
+/// impl_enum_try_from!(
+///     #[try_from(endian = "runtime")]
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///        Foo = 0x1234,
+///        Bar = 0x5678,
+///     },
+///     u16,
+///     (),
+///     ()
+/// );
+///
+/// # fn main() {
+/// let v = u16::from_be_bytes([0x12, 0x34]);
+/// assert_eq!(MyEnum::try_from_endian(v, Endian::Native), Ok(MyEnum::Foo));
+/// let v = u16::from_ne_bytes([0x56, 0x78]);
+/// assert_eq!(MyEnum::try_from_endian(v, Endian::Big), Ok(MyEnum::Bar));
+/// let v = u16::from_ne_bytes([0x78, 0x56]);
+/// assert_eq!(MyEnum::try_from_endian(v, Endian::Little), Ok(MyEnum::Bar));
+/// assert_eq!(MyEnum::Bar.to_endian(Endian::Little), v);
+/// # }
+/// ```
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///        Foo = 0x1234,
+///        Bar = 0x5678,
+///        #[try_from(other)]
+///        Unknown(u16),
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!(MyEnum::from(0x1234), MyEnum::Foo);
+/// assert_eq!(MyEnum::from(0x9abc), MyEnum::Unknown(0x9abc));
+/// assert_eq!(u16::from(MyEnum::Unknown(0x9abc)), 0x9abc);
+/// # }
+/// ```
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[try_from(impl_default)]
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///        #[try_from(default)]
+///        Unspecified = 0,
+///        Foo = 1,
+///        Bar = 2,
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!(MyEnum::from(1), MyEnum::Foo);
+/// assert_eq!(MyEnum::from(3), MyEnum::Unspecified);
+/// assert_eq!(MyEnum::default(), MyEnum::Unspecified);
+/// # }
+/// ```
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///        Foo = 0x01,
+///        Reserved = 0x10..=0x1f,
+///        Vendor(u8) = 0x80..=0xff,
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!(MyEnum::try_from(0x01), Ok(MyEnum::Foo));
+/// assert_eq!(MyEnum::try_from(0x15), Ok(MyEnum::Reserved));
+/// assert_eq!(MyEnum::try_from(0x9a), Ok(MyEnum::Vendor(0x9a)));
+/// assert!(MyEnum::try_from(0x20).is_err());
+///
+/// assert_eq!(u8::from(MyEnum::Reserved), 0x10);
+/// assert_eq!(u8::from(MyEnum::Vendor(0x9a)), 0x9a);
+/// # }
+/// ```
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static LEGACY_OPCODES: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn on_legacy_opcode(_value: u8, _opcode: &Opcode) {
+///     LEGACY_OPCODES.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// impl_enum_try_from!(
+///     #[try_from(deprecated_alias_hook = on_legacy_opcode)]
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum Opcode {
+///        #[try_from(alias = 0x85, deprecated_alias = 0x05)]
+///        Foo = 0x01,
+///        Bar = 0x02,
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!(Opcode::try_from(0x85), Ok(Opcode::Foo));
+/// assert_eq!(LEGACY_OPCODES.load(Ordering::Relaxed), 0);
+/// assert_eq!(Opcode::try_from(0x05), Ok(Opcode::Foo));
+/// assert_eq!(LEGACY_OPCODES.load(Ordering::Relaxed), 1);
+///
+/// assert_eq!(u8::from(Opcode::Foo), 0x01);
+/// # }
+/// ```
+///
+/// Conversion from the names of the variants:
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[try_from(from_str = "kebab_case")]
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum Compression {
+///        None = 0x00,
+///        #[try_from(name_alias = "zlib")]
+///        Deflate = 0x01,
+///        #[try_from(rename = "lz4")]
+///        Lz4Frame = 0x02,
+///        ZstdDictionary = 0x03,
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!("none".parse(), Ok(Compression::None));
+/// assert_eq!("zlib".parse(), Ok(Compression::Deflate));
+/// assert_eq!(Compression::try_from("lz4"), Ok(Compression::Lz4Frame));
+/// assert_eq!(Compression::try_from("zstd-dictionary"), Ok(Compression::ZstdDictionary));
+/// assert!("Deflate".parse::<Compression>().is_err());
+/// # }
+/// ```
+///
+/// Formatting the names and the values of the variants:
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[try_from(display, debug, hex)]
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq)]
+///     enum EtherType {
+///        #[try_from(rename = "ETH_P_IP")]
+///        Ipv4 = 0x0800,
+///        #[try_from(rename = "ETH_P_IPV6")]
+///        Ipv6 = 0x86dd,
+///        #[try_from(other)]
+///        Unknown(u16),
+///     }
+/// );
+///
+/// # fn main() {
+/// let ether_type = EtherType::from(0x0800);
+/// assert_eq!(format!("{ether_type} ({ether_type:#06x})"), "ETH_P_IP (0x0800)");
+/// assert_eq!(format!("{:?}", EtherType::Ipv6), "ETH_P_IPV6(0x86dd)");
+/// assert_eq!(format!("{:X}", EtherType::from(0x88cc)), "88CC");
+/// # }
+/// ```
+///
+/// Reading the enum from a buffer:
+///
+/// ```
+/// # use enum_try_from::{impl_enum_try_from_be, TryReadError};
+/// impl_enum_try_from_be!(
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum EtherType {
+///        Ipv4 = 0x0800,
+///        Ipv6 = 0x86dd,
+///     },
+///     u16,
+///     (),
+///     ()
+/// );
+///
+/// # fn main() {
+/// let packet = [0x86, 0xdd, 0x60, 0x00];
+/// assert_eq!(EtherType::try_read_from(&packet), Ok((EtherType::Ipv6, &packet[2..])));
+/// assert_eq!(EtherType::try_from([0x08, 0x00]), Ok(EtherType::Ipv4));
+/// assert_eq!(EtherType::try_from_le_bytes([0x00, 0x08]), Ok(EtherType::Ipv4));
+/// assert_eq!(
+///     EtherType::try_read_from(&packet[3..]),
+///     Err(TryReadError::Truncated { needed: 2, available: 1 })
+/// );
+/// # }
+/// ```
+///
+/// Messages with payloads, dispatched by their tags:
+///
+/// ```
+/// # use enum_try_from::{impl_enum_try_from, ReadField, TryFromReprError};
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum ParseError {
+///     UnknownTag(u8),
+///     Truncated,
+/// }
+///
+/// impl From<TryFromReprError<u8>> for ParseError {
+///     fn from(e: TryFromReprError<u8>) -> Self {
+///         ParseError::UnknownTag(e.value)
+///     }
+/// }
+///
+/// impl_enum_try_from!(
+///     #[try_from(tag_enum = MessageTag)]
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum Message {
+///        #[tag = 0x01]
+///        Ping(u32),
+///        #[tag = 0x02]
+///        Resize { width: u16, height: u16 },
+///        #[tag = 0x03]
+///        Close,
+///     }
+/// );
+///
+/// struct Reader<'a>(&'a [u8]);
+///
+/// impl Reader<'_> {
+///     fn take<const N: usize>(&mut self) -> Result<[u8; N], ParseError> {
+///         let (bytes, rest) = self.0.split_first_chunk().ok_or(ParseError::Truncated)?;
+///         self.0 = rest;
+///         Ok(*bytes)
+///     }
+/// }
+///
+/// impl ReadField<u16> for Reader<'_> {
+///     type Error = ParseError;
+///
+///     fn read_field(&mut self) -> Result<u16, ParseError> {
+///         self.take().map(u16::from_be_bytes)
+///     }
+/// }
+///
+/// impl ReadField<u32> for Reader<'_> {
+///     type Error = ParseError;
+///
+///     fn read_field(&mut self) -> Result<u32, ParseError> {
+///         self.take().map(u32::from_be_bytes)
+///     }
+/// }
+///
+/// fn parse(bytes: &[u8]) -> Result<Message, ParseError> {
+///     let mut reader = Reader(bytes);
+///     let [tag] = reader.take()?;
+///     Message::try_from_tag_and(tag, &mut reader)
+/// }
+///
+/// # fn main() {
+/// assert_eq!(parse(&[0x01, 0x00, 0x00, 0x00, 0x2a]), Ok(Message::Ping(42)));
+/// assert_eq!(
+///     parse(&[0x02, 0x02, 0x80, 0x01, 0xe0]),
+///     Ok(Message::Resize { width: 640, height: 480 })
+/// );
+/// assert_eq!(parse(&[0x02, 0x02, 0x80]), Err(ParseError::Truncated));
+/// assert_eq!(parse(&[0x03]), Ok(Message::Close));
+/// assert_eq!(parse(&[0x04]), Err(ParseError::UnknownTag(0x04)));
+/// assert_eq!(Message::Close.tag(), MessageTag::Close);
+/// assert_eq!(u8::from(MessageTag::Resize), 0x02);
+/// # }
+/// ```
+///
+/// Casting bytes to a structure with the enum, with the `bytemuck` feature:
+///
+/// ```
+/// # #[cfg(feature = "bytemuck")]
+/// # mod example {
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[try_from(bytemuck)]
+///     #[repr(u8)]
+///     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///     enum Protocol {
+///        Icmp = 1,
+///        Tcp = 6,
+///        Udp = 17,
+///     }
+/// );
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy, bytemuck::CheckedBitPattern)]
+/// struct Header {
+///     ttl: u8,
+///     protocol: Protocol,
+/// }
+///
+/// # pub fn main() {
+/// let header: &Header = bytemuck::checked::from_bytes(&[64, 6]);
+/// assert_eq!(header.protocol, Protocol::Tcp);
+/// assert!(bytemuck::checked::try_from_bytes::<Header>(&[64, 2]).is_err());
+/// # }
+/// # }
+/// # fn main() {
+/// #     #[cfg(feature = "bytemuck")]
+/// #     example::main();
+/// # }
+/// ```
+///
+/// The enum has to have the layout of its primitive type, so a `repr` changing
+/// the alignment is an error:
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[try_from(bytemuck)]
+///     #[repr(u8, align(4))]
+///     #[derive(Clone, Copy)]
+///     enum Protocol {
+///        Icmp = 1,
+///        Tcp = 6,
+///     }
+/// );
+/// ```
+///
+/// The same with the `zerocopy` feature:
+///
+/// ```
+/// # #[cfg(feature = "zerocopy")]
+/// # mod example {
+/// # use enum_try_from::impl_enum_try_from;
+/// use zerocopy::TryFromBytes;
+///
+/// impl_enum_try_from!(
+///     #[try_from(zerocopy)]
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum Protocol {
+///        Icmp = 1,
+///        Tcp = 6,
+///        Udp = 17,
+///     }
+/// );
+///
+/// #[repr(C)]
+/// #[derive(zerocopy::TryFromBytes, zerocopy::KnownLayout, zerocopy::Immutable)]
+/// struct Header {
+///     ttl: u8,
+///     protocol: Protocol,
+/// }
+///
+/// # pub fn main() {
+/// let header = Header::try_ref_from_bytes(&[64, 17]).unwrap();
+/// assert_eq!(header.protocol, Protocol::Udp);
+/// assert!(Header::try_ref_from_bytes(&[64, 2]).is_err());
+/// # }
+/// # }
+/// # fn main() {
+/// #     #[cfg(feature = "zerocopy")]
+/// #     example::main();
+/// # }
+/// ```
+///
+/// The constants with the variants:
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[try_from(consts)]
+///     #[repr(u8)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum Opcode {
+///        Foo = 0x03,
+///        Bar = 0x01,
+///        Baz = 0x02,
+///     }
+/// );
+///
+/// # fn main() {
+/// assert_eq!(Opcode::ALL, [Opcode::Bar, Opcode::Baz, Opcode::Foo]);
+/// assert_eq!(Opcode::VALUES, [0x01, 0x02, 0x03]);
+/// assert_eq!(Opcode::COUNT, 3);
+/// assert_eq!((Opcode::MIN, Opcode::MAX), (0x01, 0x03));
+/// # }
+/// ```
+///
+/// Overlapping ranges are an error:
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_enum_try_from;
+/// impl_enum_try_from!(
+///     #[repr(u8)]
+///     enum MyEnum {
+///        Reserved = 0x10..=0x1f,
+///        Vendor(u8) = 0x18..=0xff,
+///     }
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_enum_try_from {
+    ($($input:tt)*) => {
+        $crate::__impl_enum_try_from! { @attrs enum [] [] [] $($input)* }
+    };
+}
+
+/// Macro which implements the `TryFrom` trait for the given enum and type, with
+/// conversion of the input value from big endian.
+///
+/// It's a shorthand for [`impl_enum_try_from`] with the
+/// `#[try_from(endian = "be")]` option.
+///
+/// The first argument is the enum to implement the trait for.
+///
+/// The second argument is the type to implement the trait for, or a list of
+/// types. It's optional if the enum has a `repr` attribute with a primitive
+/// integer type (i.e. `#[repr(u8)]`), which is then used. Otherwise, usually
+/// `i32` or `u32` would be the best choice.
+///
+/// The third argument is the type of the error which should be returned if the
+/// value provided to `try_from` is not a valid variant of the enum.
+///
+/// The fourth argument is the concrete error value which should be returned if
+/// the value provided to `try_from` is not a valid variant of the enum.
+///
+/// The error arguments are optional, the same as in [`impl_enum_try_from`].
+///
+/// # Examples
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from_be;
+/// impl_enum_try_from_be!(
+///    #[repr(u16)]
+///    #[derive(PartialEq, Eq, Debug)]
+///    enum MyEnum {
+///       Foo = 0x1234,
+///       Bar = 0x5678,
+///       Baz = 0x9abc,
+///    },
+///    u16,
+///    (),
+///    ()
+/// );
+///
+/// # fn main() {
+/// assert_eq!(MyEnum::try_from(0x3412), Ok(MyEnum::Foo));
+/// assert_eq!(MyEnum::try_from(0x7856), Ok(MyEnum::Bar));
+/// assert_eq!(MyEnum::try_from(0xbc9a), Ok(MyEnum::Baz));
+/// assert_eq!(MyEnum::try_from(0xdef0), Err(()));
+///
+/// assert_eq!(u16::from(MyEnum::Foo), 0x3412);
+/// # }
+/// ```
+///
+/// ```
+/// use thiserror::Error;
+/// # use enum_try_from::impl_enum_try_from_be;
+///
+/// #[derive(Error, Debug, PartialEq, Eq)]
+/// pub enum MyError {
+///     #[error("invalid value")]
+///     InvalidValue,
+/// }
+///
+/// impl_enum_try_from_be!(
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///         Foo = 0x1234,
+///         Bar = 0x5678,
+///         Baz = 0x9abc,
+///     },
+///     u16,
+///     MyError,
+///     MyError::InvalidValue,
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_enum_try_from_be {
+    ($($input:tt)*) => {
+        $crate::impl_enum_try_from! { #[try_from(endian = "be")] $($input)* }
+    };
+}
+
+/// Macro which implements the `TryFrom` trait for the given enum and type, with
+/// conversion of the input value from little endian.
+///
+/// It's a shorthand for [`impl_enum_try_from`] with the
+/// `#[try_from(endian = "le")]` option.
+///
+/// The first argument is the enum to implement the trait for.
+///
+/// The second argument is the type to implement the trait for, or a list of
+/// types. It's optional if the enum has a `repr` attribute with a primitive
+/// integer type (i.e. `#[repr(u8)]`), which is then used. Otherwise, usually
+/// `i32` or `u32` would be the best choice.
+///
+/// The third argument is the type of the error which should be returned if the
+/// value provided to `try_from` is not a valid variant of the enum.
+///
+/// The fourth argument is the concrete error value which should be returned if
+/// the value provided to `try_from` is not a valid variant of the enum.
+///
+/// The error arguments are optional, the same as in [`impl_enum_try_from`].
+///
+/// # Examples
+///
+/// ```
+/// # use enum_try_from::impl_enum_try_from_le;
+/// impl_enum_try_from_le!(
+///    #[repr(u16)]
+///    #[derive(PartialEq, Eq, Debug)]
+///    enum MyEnum {
+///       Foo = 0x1234,
+///       Bar = 0x5678,
+///       Baz = 0x9abc,
+///    },
+///    u16,
+///    (),
+///    ()
+/// );
+///
+/// # fn main() {
+/// assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0x34, 0x12])), Ok(MyEnum::Foo));
+/// assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0x78, 0x56])), Ok(MyEnum::Bar));
+/// assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0xbc, 0x9a])), Ok(MyEnum::Baz));
+/// assert_eq!(MyEnum::try_from(u16::from_ne_bytes([0xf0, 0xde])), Err(()));
+///
+/// assert_eq!(u16::from(MyEnum::Foo).to_ne_bytes(), [0x34, 0x12]);
+/// # }
+/// ```
+///
+/// ```
+/// use thiserror::Error;
+/// # use enum_try_from::impl_enum_try_from_le;
+///
+/// #[derive(Error, Debug, PartialEq, Eq)]
+/// pub enum MyError {
+///     #[error("invalid value")]
+///     InvalidValue,
+/// }
+///
+/// impl_enum_try_from_le!(
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum MyEnum {
+///         Foo = 0x1234,
+///         Bar = 0x5678,
+///         Baz = 0x9abc,
+///     },
+///     u16,
+///     MyError,
+///     MyError::InvalidValue,
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_enum_try_from_le {
+    ($($input:tt)*) => {
+        $crate::impl_enum_try_from! { #[try_from(endian = "le")] $($input)* }
+    };
+}
+
+/// Macro which implements the `TryFrom` trait for the given enum of flags and
+/// defines a set of the flags.
+///
+/// The first argument is the enum, of which every variant is a single bit. The
+/// conversions of the enum itself are implemented the same way as with
+/// [`impl_enum_try_from`], returning [`TryFromReprError`].
+///
+/// The second argument is the name of the flag set, which is defined as a
+/// tuple struct holding the bits, with the same visibility as the enum.
+///
+/// The third argument is the type to implement the trait for. It's optional
+/// if the enum has a `repr` attribute with a primitive integer type.
+///
+/// The flag set implements `TryFrom` for the type, which returns
+/// [`TryFromFlagsError`] if any bit of the value doesn't match a flag, and the
+/// reverse `From` conversions. The `from_bits_truncate` method converts the
+/// value dropping the unknown bits instead. The set can be combined from flags
+/// and other sets with `|` and `&`, collected from an iterator of flags, and
+/// its flags can be iterated with the `iter` method.
+///
+/// The `endian` and `strategy` options of `#[try_from(...)]` are supported the
+/// same way as with [`impl_enum_try_from`]. Other options are not supported.
+///
+/// # Examples
+///
+/// ```
+/// # use enum_try_from::impl_flags_try_from;
+/// impl_flags_try_from!(
+///     #[repr(u8)]
+///     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///     pub enum Permission {
+///         Read = 0x1,
+///         Write = 0x2,
+///         Execute = 0x4,
+///     },
+///     Permissions
+/// );
+///
+/// # fn main() {
+/// let permissions = Permissions::try_from(0x5).unwrap();
+/// assert!(permissions.contains(Permission::Read));
+/// assert!(!permissions.contains(Permission::Write));
+/// assert_eq!(
+///     permissions.iter().collect::<Vec<_>>(),
+///     [Permission::Read, Permission::Execute],
+/// );
+/// assert_eq!(permissions, Permission::Read | Permission::Execute);
+/// assert_eq!(format!("{permissions:?}"), "Permissions(Read | Execute)");
+///
+/// let err = Permissions::try_from(0x9).unwrap_err();
+/// assert_eq!(err.unknown_bits, 0x8);
+/// assert_eq!(Permissions::from_bits_truncate(0x9), Permission::Read.into());
+///
+/// assert_eq!(u8::from(Permission::Read | Permission::Write), 0x3);
+/// assert_eq!(Permission::try_from(0x2), Ok(Permission::Write));
+/// # }
+/// ```
+///
+/// Variants which are not a single bit are an error:
+///
+/// ```compile_fail
+/// # use enum_try_from::impl_flags_try_from;
+/// impl_flags_try_from!(
+///     #[repr(u8)]
+///     enum Permission {
+///         Read = 0x1,
+///         ReadWrite = 0x3,
+///     },
+///     Permissions
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_flags_try_from {
+    ($($input:tt)*) => {
+        $crate::__impl_enum_try_from! { @attrs flags [] [] [] $($input)* }
+    };
+}
+
+/// Macro which implements the `TryFrom` trait for the given enum of flags and
+/// defines a set of the flags, with conversion of the input value from big
+/// endian.
+///
+/// It's a shorthand for [`impl_flags_try_from`] with the
+/// `#[try_from(endian = "be")]` option.
+///
+/// # Examples
+///
+/// ```
+/// # use enum_try_from::impl_flags_try_from_be;
+/// impl_flags_try_from_be!(
+///     #[repr(u16)]
+///     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///     enum Flag {
+///         Syn = 0x0002,
+///         Ack = 0x0010,
+///         Ece = 0x0040,
+///     },
+///     Flags
+/// );
+///
+/// # fn main() {
+/// let flags = Flags::try_from(u16::from_ne_bytes([0x00, 0x12])).unwrap();
+/// assert_eq!(flags, Flag::Syn | Flag::Ack);
+/// assert_eq!(flags.bits(), 0x12);
+/// assert_eq!(u16::from(flags).to_ne_bytes(), [0x00, 0x12]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_flags_try_from_be {
+    ($($input:tt)*) => {
+        $crate::impl_flags_try_from! { #[try_from(endian = "be")] $($input)* }
+    };
+}
+
+/// Macro which implements the `TryFrom` trait for the given enum of flags and
+/// defines a set of the flags, with conversion of the input value from little
+/// endian.
+///
+/// It's a shorthand for [`impl_flags_try_from`] with the
+/// `#[try_from(endian = "le")]` option.
+///
+/// # Examples
+///
+/// ```
+/// # use enum_try_from::impl_flags_try_from_le;
+/// impl_flags_try_from_le!(
+///     #[repr(u16)]
+///     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+///     enum Flag {
+///         Foo = 0x0100,
+///         Bar = 0x0001,
+///     },
+///     Flags
+/// );
+///
+/// # fn main() {
+/// let flags = Flags::try_from(u16::from_ne_bytes([0x00, 0x01])).unwrap();
+/// assert_eq!(flags, Flags::from(Flag::Foo));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_flags_try_from_le {
+    ($($input:tt)*) => {
+        $crate::impl_flags_try_from! { #[try_from(endian = "le")] $($input)* }
+    };
+}
+
+/// Macro which converts a value known at compile time to a variant of an enum
+/// generated by the macros of this crate, or deriving `TryFromRepr`.
+///
+/// The value is a literal or a constant in the byte order of `try_from`, which
+/// is converted with the `from_repr` const function of the enum. If it doesn't
+/// match any variant, the compilation fails with an error naming the enum and
+/// the value, instead of panicking at runtime like
+/// `MyEnum::try_from(value).unwrap()`.
+///
+/// # Examples
+///
+/// ```
+/// use enum_try_from::{const_try_from, impl_enum_try_from, impl_enum_try_from_be};
+///
+/// impl_enum_try_from!(
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum EtherType {
+///         Ipv4 = 0x0800,
+///         Ipv6 = 0x86dd,
+///     }
+/// );
+///
+/// impl_enum_try_from_be!(
+///     #[repr(u16)]
+///     #[derive(PartialEq, Eq, Debug)]
+///     enum EtherTypeBe {
+///         Ipv4 = 0x0800,
+///         Ipv6 = 0x86dd,
+///     }
+/// );
+///
+/// const IPV6: EtherType = const_try_from!(EtherType, 0x86dd);
+///
+/// # fn main() {
+/// assert_eq!(IPV6, EtherType::Ipv6);
+/// assert_eq!(const_try_from!(EtherTypeBe, 0x0800_u16.to_be()), EtherTypeBe::Ipv4);
+/// # }
+/// ```
+///
+/// An invalid value fails the compilation with
+/// ``invalid value 0x88cc for `EtherType` ``:
+///
+/// ```compile_fail
+/// # use enum_try_from::{const_try_from, impl_enum_try_from};
+/// # impl_enum_try_from!(
+/// #     #[repr(u16)]
+/// #     enum EtherType {
+/// #         Ipv4 = 0x0800,
+/// #         Ipv6 = 0x86dd,
+/// #     }
+/// # );
+/// const LLDP: EtherType = const_try_from!(EtherType, 0x88cc);
+/// ```
+#[macro_export]
+macro_rules! const_try_from {
+    ($name:path, $v:expr $(,)?) => {{
+        const VARIANT: $name = match <$name>::from_repr($v) {
+            Some(variant) => variant,
+            None => panic!(
+                "{}",
+                concat!(
+                    "invalid value ",
+                    stringify!($v),
+                    " for `",
+                    stringify!($name),
+                    "`"
+                ),
+            ),
+        };
+        VARIANT
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from {
+    // Separate the `#[try_from(...)]` options from the attributes of the enum
+    // and collect the content of `#[repr(...)]` attributes.
+    (@attrs $mode:ident [$($opts:tt)*] [$($meta:tt)*] [$($repr:tt)*] #[try_from($($opt:tt)*)]
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @attrs $mode [$($opts)* $($opt)*,] [$($meta)*] [$($repr)*] $($rest)*
+        }
+    };
+    (@attrs $mode:ident [$($opts:tt)*] [$($meta:tt)*] [$($repr:tt)*] #[repr($($r:tt)*)]
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @attrs $mode [$($opts)*] [$($meta)* #[repr($($r)*)]] [$($repr)* $($r)*,] $($rest)*
+        }
+    };
+    (@attrs $mode:ident [$($opts:tt)*] [$($meta:tt)*] [$($repr:tt)*] #[$($attr:tt)*]
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @attrs $mode [$($opts)*] [$($meta)* #[$($attr)*]] [$($repr)*] $($rest)*
+        }
+    };
+    (@attrs enum [$($opts:tt)*] [$($meta:tt)*] [$($repr:tt)*] $vis:vis enum $name:ident {
+        $($variants:tt)*
+    } $(, $($args:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @repr [$($repr)*] { @args [[$($opts)*] [$($meta)*]] [$vis enum $name { $($variants)* }] }
+            [] [] [] $($($args)*)?
+        }
+    };
+    (@attrs flags [$($opts:tt)*] [$($meta:tt)*] [$($repr:tt)*] $vis:vis enum $name:ident {
+        $($variants:tt)*
+    }, $set:ident $(, $($args:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @repr [$($repr)*] {
+                @flags_args [[$($opts)*] [$($meta)*]] [$vis enum $name { $($variants)* }] $set
+            }
+            [$($($args)*)?]
+        }
+    };
+    (@attrs flags $($rest:tt)*) => {
+        compile_error!("expected an enum followed by the name of the flag set and `[TYPE]`");
+    };
+
+    // Find the primitive integer type in the content of `#[repr(...)]` and pass
+    // it to the continuation as `[PRIMITIVE]`, or as `[]` if there is none.
+    (@repr [$r:tt $($repr:tt)*] { $($k:tt)* } $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @primitive $r
+            { $($k)* [$r] $($rest)* }
+            { @repr [$($repr)*] { $($k)* } $($rest)* }
+        }
+    };
+    (@repr [] { $($k:tt)* } $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { $($k)* [] $($rest)* }
+    };
+
+    (@primitive u8 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive u16 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive u32 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive u64 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive u128 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive usize { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive i8 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive i16 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive i32 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive i64 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive i128 { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive isize { $($yes:tt)* } $no:tt) => { $crate::__impl_enum_try_from! { $($yes)* } };
+    (@primitive $other:tt $yes:tt { $($no:tt)* }) => { $crate::__impl_enum_try_from! { $($no)* } };
+
+    // Parse the arguments following the enum. The type argument is optional,
+    // so count the top-level commas first, as trying to parse an error value as
+    // a type (or the other way around) is a hard error.
+    // The depth of the angle brackets is tracked in the last group, so the
+    // commas between generic arguments, i.e. in `MyError<u16, u8>`, are not
+    // counted.
+    (@args $attrs:tt $enum:tt $prim:tt [$($n:tt)*] [$($args:tt)*] [] , $($rest:tt)+) => {
+        $crate::__impl_enum_try_from! {
+            @args $attrs $enum $prim [$($n)* +] [$($args)* ,] [] $($rest)+
+        }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt [$($args:tt)*] $depth:tt $(move)? | $($rest:tt)*) => {
+        // A closure is the last argument, don't count the commas between its
+        // parameters.
+        $crate::__impl_enum_try_from! { @args $attrs $enum $prim $n [$($args)* | $($rest)*] }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt [$($args:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @args $attrs $enum $prim $n [$($args)* <] [< $($depth)*] $($rest)*
+        }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt [$($args:tt)*] [< $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @args $attrs $enum $prim $n [$($args)* >] [$($depth)*] $($rest)*
+        }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt [$($args:tt)*] [< < $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @args $attrs $enum $prim $n [$($args)* >>] [$($depth)*] $($rest)*
+        }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt [$($args:tt)*] $depth:tt $arg:tt $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @args $attrs $enum $prim $n [$($args)* $arg] $depth $($rest)*
+        }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt $args:tt $depth:tt) => {
+        $crate::__impl_enum_try_from! { @args $attrs $enum $prim $n $args }
+    };
+    (@args [$($attrs:tt)*] [$($enum:tt)*] $prim:tt $n:tt [[$type:ty $(, $extra:ty)* $(,)?] $(,)?]) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* $prim $($enum)*, $type [$($extra),*], {}
+        }
+    };
+    (@args [$($attrs:tt)*] [$($enum:tt)*] $prim:tt [+ +]
+        [[$type:ty $(, $extra:ty)* $(,)?], $err_ty:ty, $($err:tt)+]) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* $prim $($enum)*, $type [$($extra),*],
+            { $err_ty, $($err)+ }
+        }
+    };
+    (@args [$($attrs:tt)*] [$($enum:tt)*] [$prim:ident] [] []) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* [$prim] $($enum)*, $prim [], {}
+        }
+    };
+    (@args [$($attrs:tt)*] [$($enum:tt)*] [$prim:ident] [+] [$err_ty:ty, $($err:tt)+]) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* [$prim] $($enum)*, $prim [], { $err_ty, $($err)+ }
+        }
+    };
+    (@args $attrs:tt $enum:tt [] $n:tt []) => {
+        compile_error!(
+            "cannot infer the type to convert from, provide it as an argument or \
+             add a `#[repr(...)]` attribute with a primitive integer type"
+        );
+    };
+    (@args $attrs:tt $enum:tt [] [+] $args:tt) => {
+        compile_error!(
+            "cannot infer the type to convert from, provide it as an argument or \
+             add a `#[repr(...)]` attribute with a primitive integer type"
+        );
+    };
+    (@args [$($attrs:tt)*] [$($enum:tt)*] $prim:tt [] [$type:ty $(,)?]) => {
+        $crate::__impl_enum_try_from! { @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* $prim $($enum)*, $type [], {} }
+    };
+    (@args [$($attrs:tt)*] [$($enum:tt)*] $prim:tt [+ +] [$type:ty, $err_ty:ty, $($err:tt)+]) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* $prim $($enum)*, $type [], { $err_ty, $($err)+ }
+        }
+    };
+    (@args $attrs:tt $enum:tt $prim:tt $n:tt $args:tt) => {
+        compile_error!("expected `[TYPE[, ERROR_TYPE, ERROR]]` arguments after the enum");
+    };
+
+    // Parse the arguments following the flag set.
+    (@flags_args [$($attrs:tt)*] [$($enum:tt)*] $set:ident [$prim:ident] []) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* [$prim] @flags $($enum)* $set, $prim
+        }
+    };
+    (@flags_args $attrs:tt $enum:tt $set:ident [] []) => {
+        compile_error!(
+            "cannot infer the type to convert from, provide it as an argument or \
+             add a `#[repr(...)]` attribute with a primitive integer type"
+        );
+    };
+    (@flags_args [$($attrs:tt)*] [$($enum:tt)*] $set:ident $prim:tt [$type:ty $(,)?]) => {
+        $crate::__impl_enum_try_from! {
+            @opts [ne value no auto [] [no no no] no [no no] [] no] $($attrs)* $prim @flags $($enum)* $set, $type
+        }
+    };
+    (@flags_args $attrs:tt $enum:tt $set:ident $prim:tt $args:tt) => {
+        compile_error!("expected `[TYPE]` argument after the flag set");
+    };
+
+    // Parse the options into
+    // `[ENDIAN ERROR_KIND DEFAULT_IMPL STRATEGY [FROM_STR] [DISPLAY DEBUG HEX] [HOOK]]`.
+    (@opts $state:tt [, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts $state [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $($state:tt)*] [endian = "ne", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts [ne $($state)*] [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $($state:tt)*] [endian = "be", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts [be $($state)*] [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $($state:tt)*] [endian = "le", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts [le $($state)*] [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $($state:tt)*] [endian = "runtime", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts [runtime $($state)*] [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $error:ident $($state:tt)*] [error_fn, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts [$endian fn $($state)*] [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $($state:tt)*] [impl_default, $($opts:tt)*]
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @opts [$endian $error impl $($state)*] [$($opts)*] $($rest)* }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $($state:tt)*]
+        [strategy = "auto", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default auto $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $($state:tt)*]
+        [strategy = "match", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default match $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $($state:tt)*]
+        [strategy = "table", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default table $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $($state:tt)*]
+        [strategy = "binary_search", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default binary_search $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $($state:tt)*]
+        [from_str, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy [Exact] $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $($state:tt)*]
+        [from_str = "exact", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy [Exact] $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $($state:tt)*]
+        [from_str = "case_insensitive", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy [CaseInsensitive] $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $($state:tt)*]
+        [from_str = "snake_case", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy [SnakeCase] $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $($state:tt)*]
+        [from_str = "kebab_case", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy [KebabCase] $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt
+        [$display:ident $debug:ident $hex:ident] $($state:tt)*] [display, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str [display $debug $hex] $($state)*]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt
+        [$display:ident $debug:ident $hex:ident] $($state:tt)*] [debug, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str [$display debug $hex] $($state)*]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt
+        [$display:ident $debug:ident $hex:ident] $($state:tt)*] [hex, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str [$display $debug hex] $($state)*]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        $($state:tt)*] [serde, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt value $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        $($state:tt)*] [serde = "value", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt value $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        $($state:tt)*] [serde = "name", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt name $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        $($state:tt)*] [serde = "lenient", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt lenient $($state)*] [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        [$bytemuck:ident $zerocopy:tt] $hook:tt $consts:ident] [bytemuck, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt $serde [checked $zerocopy] $hook $consts]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        [$bytemuck:ident $zerocopy:tt] $hook:tt $consts:ident] [bytemuck = "contiguous", $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt $serde [contiguous $zerocopy] $hook $consts]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        [$bytemuck:ident $zerocopy:tt] $hook:tt $consts:ident] [zerocopy, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt $serde [$bytemuck zerocopy] $hook $consts]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        [$bytemuck:ident $zerocopy:tt] $hook:tt $consts:ident] [zerocopy = $path:tt, $($opts:tt)*]
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt $serde [$bytemuck $path] $hook $consts]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        $layout:tt $hook:tt $consts:ident] [deprecated_alias_hook = $h:path, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt $serde $layout [$h] $consts]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts [$endian:ident $error:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+        $layout:tt $hook:tt $consts:ident] [consts, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts [$endian $error $default $strategy $from_str $fmt $serde $layout $hook consts]
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@opts $state:tt [tag_enum = $tag:ident, $($opts:tt)*] $meta:tt $prim:tt $vis:vis enum
+        $name:ident $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @opts $state [$($opts)*] $meta $prim $vis enum $name @tag $tag $($rest)*
+        }
+    };
+    (@opts $state:tt [] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @enum $state $($rest)* }
+    };
+    (@opts $state:tt [$($opts:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("invalid `try_from` options: ", stringify!($($opts)*)));
+    };
+
+    (@enum $state:tt $meta:tt $prim:tt $vis:vis enum $name:ident @tag $tag:ident {
+        $($variants:tt)*
+    }, $type:ty [$($extra:ty),*], $error:tt) => {
+        $crate::__impl_enum_try_from! {
+            @tagged { $state $meta $prim $vis $name $tag, $type [$($extra),*], $error }
+            [] [] [] [] [] [] []
+            $($variants)*
+        }
+    };
+    (@enum [$endian:ident value no $strategy:tt [] [no no no] no [no no] [] no] $meta:tt $prim:tt @flags $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @flags [$endian $strategy] $meta $prim $($rest)* }
+    };
+    (@enum $state:tt $meta:tt $prim:tt @flags $($rest:tt)*) => {
+        compile_error!("only the `endian` and `strategy` options are supported for flags");
+    };
+    (@enum [$endian:ident fn $($state:tt)*] $meta:tt $prim:tt $vis:vis enum $name:ident
+        $variants:tt, $type:ty [$($extra:ty),*], { $err_ty:ty, $($err:tt)* }) => {
+        $crate::__impl_enum_try_from! {
+            @enum [$endian value $($state)*] $meta $prim $vis enum $name $variants,
+            $type [$($extra),*], { $err_ty, @fn $($err)* }
+        }
+    };
+    (@enum [$endian:ident fn $($state:tt)*] $($rest:tt)*) => {
+        compile_error!("the `error_fn` option requires the error arguments");
+    };
+    // Take all variants at once if they are unit variants without
+    // `#[try_from(...)]` options, so enums with hundreds of variants don't
+    // exceed the recursion limit, also when they have other attributes, i.e.
+    // doc comments. The discriminants have to be literals, as `$val:expr` would
+    // consume ranges.
+    (@enum [$endian:ident value $default:ident $($state:tt)*] $meta:tt $prim:tt $vis:vis enum $name:ident {
+        $($(#[$head:ident $($attr:tt)*])* $vname:ident $(= $val:literal)?),+ $(,)?
+    }, $type:ty [$($extra:ty),*], $error:tt) => {
+        $crate::__impl_enum_try_from! {
+            @has_try_from [$($($head)*)*]
+            {
+                @variants {
+                    [$endian $default $($state)*] $meta $prim $vis $name, $type [$($extra),*], $error
+                }
+                [] [] [] [] [] [] [] { [] [] [] [] [] }
+                $($(#[$head $($attr)*])* $vname $(= $val)?,)+
+            }
+            {
+                @variants {
+                    [$endian $default $($state)*] $meta $prim $vis $name, $type [$($extra),*], $error
+                }
+                [$($(#[$head $($attr)*])* $vname $(= $val)?,)+] [$($vname $(= $val)?,)+]
+                [$($vname [] [] [] [],)+] [] [] [] [] { [] [] [] [] [] }
+            }
+        }
+    };
+    (@enum [$endian:ident value $default:ident $($state:tt)*] $meta:tt $prim:tt $vis:vis enum $name:ident {
+        $($variants:tt)*
+    }, $type:ty [$($extra:ty),*], $error:tt) => {
+        $crate::__impl_enum_try_from! {
+            @variants {
+                [$endian $default $($state)*] $meta $prim $vis $name, $type [$($extra),*], $error
+            }
+            [] [] [] [] [] [] [] { [] [] [] [] [] }
+            $($variants)*
+        }
+    };
+
+    // Continue with the first group if the names of the attributes include
+    // `try_from`, or with the second one otherwise. The names are checked eight
+    // at a time to keep the recursion shallow.
+    (@has_try_from [] $yes:tt { $($no:tt)* }) => {
+        $crate::__impl_enum_try_from! { $($no)* }
+    };
+    (@has_try_from [try_from $($rest:ident)*] { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from [$a:ident try_from $($rest:ident)*] { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from [$a:ident $b:ident try_from $($rest:ident)*] { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from [$a:ident $b:ident $c:ident try_from $($rest:ident)*] { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from [$a:ident $b:ident $c:ident $d:ident try_from $($rest:ident)*] { $($yes:tt)* }
+        $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from [$a:ident $b:ident $c:ident $d:ident $e:ident try_from $($rest:ident)*]
+        { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from [$a:ident $b:ident $c:ident $d:ident $e:ident $f:ident try_from $($rest:ident)*]
+        { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from
+        [$a:ident $b:ident $c:ident $d:ident $e:ident $f:ident $g:ident try_from $($rest:ident)*]
+        { $($yes:tt)* } $no:tt) => {
+        $crate::__impl_enum_try_from! { $($yes)* }
+    };
+    (@has_try_from
+        [$a:ident $b:ident $c:ident $d:ident $e:ident $f:ident $g:ident $h:ident $($rest:ident)*]
+        $yes:tt $no:tt) => {
+        $crate::__impl_enum_try_from! { @has_try_from [$($rest)*] $yes $no }
+    };
+    (@has_try_from [$($rest:ident)*] $yes:tt { $($no:tt)* }) => {
+        $crate::__impl_enum_try_from! { $($no)* }
+    };
+
+    // Parse the variants into
+    // `[VARIANTS] [DISCRIMINANTS] [UNITS] [UNIT_RANGES] [TUPLE_RANGES] [FALLBACK]`,
+    // separating the `#[try_from(...)]` options from the attributes of each
+    // variant, which are collected in the last two groups. See `@impl` for the
+    // meaning of the groups.
+    // Take eight plain unit variants at a time, so enums with hundreds of
+    // variants don't exceed the recursion limit also when some of them have
+    // `#[try_from(...)]` options. Only the attributes with no arguments or a
+    // literal value, i.e. doc comments, are allowed, as they can't be
+    // `#[try_from(...)]`.
+    (@variants $ctx:tt [$($out:tt)*] [$($discr:tt)*] [$($units:tt)*] $uranges:tt $tranges:tt
+        $fallback:tt [] { [] [] [] [] [] }
+        $(#[$a1:ident $(= $b1:literal)?])* $v1:ident $(= $x1:literal)?,
+        $(#[$a2:ident $(= $b2:literal)?])* $v2:ident $(= $x2:literal)?,
+        $(#[$a3:ident $(= $b3:literal)?])* $v3:ident $(= $x3:literal)?,
+        $(#[$a4:ident $(= $b4:literal)?])* $v4:ident $(= $x4:literal)?,
+        $(#[$a5:ident $(= $b5:literal)?])* $v5:ident $(= $x5:literal)?,
+        $(#[$a6:ident $(= $b6:literal)?])* $v6:ident $(= $x6:literal)?,
+        $(#[$a7:ident $(= $b7:literal)?])* $v7:ident $(= $x7:literal)?,
+        $(#[$a8:ident $(= $b8:literal)?])* $v8:ident $(= $x8:literal)?,
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [
+                $($out)*
+                $(#[$a1 $(= $b1)?])* $v1 $(= $x1)?,
+                $(#[$a2 $(= $b2)?])* $v2 $(= $x2)?,
+                $(#[$a3 $(= $b3)?])* $v3 $(= $x3)?,
+                $(#[$a4 $(= $b4)?])* $v4 $(= $x4)?,
+                $(#[$a5 $(= $b5)?])* $v5 $(= $x5)?,
+                $(#[$a6 $(= $b6)?])* $v6 $(= $x6)?,
+                $(#[$a7 $(= $b7)?])* $v7 $(= $x7)?,
+                $(#[$a8 $(= $b8)?])* $v8 $(= $x8)?,
+            ]
+            [
+                $($discr)*
+                $v1 $(= $x1)?, $v2 $(= $x2)?, $v3 $(= $x3)?, $v4 $(= $x4)?, $v5 $(= $x5)?,
+                $v6 $(= $x6)?, $v7 $(= $x7)?, $v8 $(= $x8)?,
+            ]
+            [
+                $($units)*
+                $v1 [] [] [] [], $v2 [] [] [] [], $v3 [] [] [] [], $v4 [] [] [] [],
+                $v5 [] [] [] [], $v6 [] [] [] [], $v7 [] [] [] [], $v8 [] [] [] [],
+            ]
+            $uranges $tranges $fallback [] { [] [] [] [] [] }
+            $($rest)*
+        }
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        $vattrs:tt $vopts:tt #[try_from($($opt:tt)*)] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts [$ctx $out $discr $units $uranges $tranges $fallback $vattrs] $vopts
+            [$($opt)*,] $($rest)*
+        }
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        [$($vattrs:tt)*] $vopts:tt #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx $out $discr $units $uranges $tranges $fallback
+            [$($vattrs)* #[$($attr)*]] $vopts $($rest)*
+        }
+    };
+    (@variants $ctx:tt [$($out:tt)*] [$($discr:tt)*] $units:tt $uranges:tt $tranges:tt []
+        [$($vattrs:tt)*] { [other] [] [] [] [] } $vname:ident($($fields:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [$($out)* $($vattrs)* $vname($($fields)*),] [$($discr)* $vname,]
+            $units $uranges $tranges [other $vname] [] { [] [] [] [] [] }
+            $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt [$($out:tt)*] [$($discr:tt)*] [$($units:tt)*] $uranges:tt $tranges:tt []
+        [$($vattrs:tt)*] { [default] $aliases:tt $deprecated:tt $rename:tt $names:tt }
+        $vname:ident $(= $val:expr)? $(, $($rest:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [$($out)* $($vattrs)* $vname $(= $val)?,] [$($discr)* $vname $(= $val)?,]
+            [$($units)* $vname $aliases $deprecated $rename $names,] $uranges $tranges
+            [default $vname]
+            [] { [] [] [] [] [] }
+            $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt
+        [$kind:ident $fallback:ident] $vattrs:tt { [$vkind:ident] $($vopts:tt)* }
+        $vname:ident $($rest:tt)*) => {
+        compile_error!(concat!(
+            "only one variant can be marked with `#[try_from(other)]` or ",
+            "`#[try_from(default)]`, found `", stringify!($fallback), "` and `",
+            stringify!($vname), "`",
+        ));
+    };
+    // Take all remaining variants at once if they are plain unit variants, the
+    // same as above. The discriminants have to be literals, as `$val:expr`
+    // would consume ranges.
+    (@variants $ctx:tt [$($out:tt)*] [$($discr:tt)*] [$($units:tt)*] $uranges:tt $tranges:tt
+        $fallback:tt [] { [] [] [] [] [] }
+        $($(#[$attr:ident $(= $attr_val:literal)?])* $vname:ident $(= $val:literal)?),+ $(,)?) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [$($out)* $($(#[$attr $(= $attr_val)?])* $vname $(= $val)?,)+]
+            [$($discr)* $($vname $(= $val)?,)+]
+            [$($units)* $($vname [] [] [] [],)+] $uranges $tranges $fallback [] { [] [] [] [] [] }
+        }
+    };
+    // The bounds of ranges have to be single tokens (or literals, to allow
+    // negative numbers), as `$start:expr` would consume the whole range.
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        $vattrs:tt { [] [] [] [] [] } $vname:ident $(($($fields:tt)*))? = $start:literal ..= $end:literal
+        $(, $($rest:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @range $ctx $out $discr $units $uranges $tranges $fallback $vattrs
+            $vname $(($($fields)*))? [$start, $end] $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        $vattrs:tt { [] [] [] [] [] } $vname:ident $(($($fields:tt)*))? = $start:tt ..= $end:tt
+        $(, $($rest:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @range $ctx $out $discr $units $uranges $tranges $fallback $vattrs
+            $vname $(($($fields)*))? [$start, $end] $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        $vattrs:tt $vopts:tt $vname:ident $(($($fields:tt)*))? = $start:literal ..= $end:literal
+        $($rest:tt)*) => {
+        compile_error!(concat!(
+            "variant `", stringify!($vname), "` with a range can't have `try_from` options",
+        ));
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        $vattrs:tt $vopts:tt $vname:ident $(($($fields:tt)*))? = $start:tt ..= $end:tt
+        $($rest:tt)*) => {
+        compile_error!(concat!(
+            "variant `", stringify!($vname), "` with a range can't have `try_from` options",
+        ));
+    };
+    (@variants $ctx:tt [$($out:tt)*] [$($discr:tt)*] [$($units:tt)*] $uranges:tt $tranges:tt
+        $fallback:tt [$($vattrs:tt)*] { [] $aliases:tt $deprecated:tt $rename:tt $names:tt }
+        $vname:ident $(= $val:expr)? $(, $($rest:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [$($out)* $($vattrs)* $vname $(= $val)?,] [$($discr)* $vname $(= $val)?,]
+            [$($units)* $vname $aliases $deprecated $rename $names,] $uranges $tranges $fallback
+            [] { [] [] [] [] [] }
+            $($($rest)*)?
+        }
+    };
+    (@variants $ctx:tt $out:tt $discr:tt $units:tt $uranges:tt $tranges:tt $fallback:tt
+        $vattrs:tt $vopts:tt $vname:ident $($rest:tt)*) => {
+        compile_error!(concat!(
+            "unsupported variant `", stringify!($vname), "`, expected a unit variant, a unit \
+             variant with `#[try_from(default)]`, a tuple variant with `#[try_from(other)]` or \
+             a variant with a range of values and no aliases",
+        ));
+    };
+    (@variants {
+        [$endian:ident $default:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident
+            [$bytemuck:ident $zerocopy:tt] [$($hook:path)?] $consts:ident]
+        [$(#[$meta:meta])*] $prim:tt $vis:vis
+        $name:ident, $type:ty [$($extra:ty),*], $error:tt
+    } [$($out:tt)*] [$($discr:tt)*] [$($units:tt)*] [$($uranges:tt)*] [$($tranges:tt)*]
+        $fallback:tt [] { [] [] [] [] [] }) => {
+        $crate::__impl_enum_try_from_zerocopy! {
+            [$zerocopy]
+            $(#[$meta])*
+            $vis enum $name {
+                $($out)*
+            }
+        }
+
+        $crate::__impl_enum_try_from! {
+            @impl [$endian $strategy $from_str $fmt $serde $bytemuck $consts $($hook)?] $vis $name $prim
+            { $($discr)* }
+            { $($units)* }
+            { $($uranges)* } { $($tranges)* } $fallback, $type [$($extra),*], $error
+        }
+        $crate::__impl_enum_try_from! { @default_impl [$default] $name $fallback }
+    };
+
+    // Parse the variants of an enum with the `tag_enum` option into
+    // `[VARIANTS] [TAGS] [FIELDS] [TYPES]`, where `TAGS` are the unit variants
+    // of the tag enum with the `#[try_from(...)]` options of the variants,
+    // `FIELDS` are the variants with their fields in the form of
+    // `Name [(TYPE, ...)]` or `Name [{ FIELD: TYPE, ... }]`, and `TYPES` are the
+    // types of all fields. The attributes and the tag of the current variant
+    // are collected in the last three groups.
+    (@tagged $ctx:tt $out:tt $tags:tt $fields:tt $types:tt $attrs:tt $tattrs:tt []
+        #[tag = $t:expr] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @tagged $ctx $out $tags $fields $types $attrs $tattrs [$t] $($rest)*
+        }
+    };
+    (@tagged $ctx:tt $out:tt $tags:tt $fields:tt $types:tt $attrs:tt [$($tattrs:tt)*] $tag:tt
+        #[try_from($($opts:tt)*)] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @tagged $ctx $out $tags $fields $types $attrs [$($tattrs)* #[try_from($($opts)*)]] $tag
+            $($rest)*
+        }
+    };
+    (@tagged $ctx:tt $out:tt $tags:tt $fields:tt $types:tt [$($attrs:tt)*] $tattrs:tt $tag:tt
+        #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @tagged $ctx $out $tags $fields $types [$($attrs)* #[$($attr)*]] $tattrs $tag $($rest)*
+        }
+    };
+    (@tagged $ctx:tt [$($out:tt)*] [$($tags:tt)*] [$($fields:tt)*] [$($types:tt)*]
+        [$($attrs:tt)*] [$($tattrs:tt)*] [$t:expr]
+        $vname:ident $(($($(#[$fmeta:meta])* $fty:ty),* $(,)?))?
+        $({$($(#[$smeta:meta])* $sname:ident: $sty:ty),* $(,)?})? $(, $($rest:tt)*)?) => {
+        $crate::__impl_enum_try_from! {
+            @tagged $ctx
+            [
+                $($out)* $($attrs)* $vname $(($($(#[$fmeta])* $fty),*))?
+                $({$($(#[$smeta])* $sname: $sty),*})?,
+            ]
+            [$($tags)* $($tattrs)* $vname = $t,]
+            [$($fields)* $vname [$(($($fty),*))? $({$($sname: $sty),*})?],]
+            [$($types)* $($($fty,)*)? $($($sty,)*)?]
+            [] [] [] $($($rest)*)?
+        }
+    };
+    (@tagged $ctx:tt $out:tt $tags:tt $fields:tt $types:tt $attrs:tt $tattrs:tt []
+        $vname:ident $($rest:tt)*) => {
+        compile_error!(concat!(
+            "variant `", stringify!($vname), "` has no `#[tag = ...]` attribute",
+        ));
+    };
+    (@tagged {
+        [$endian:ident $err_kind:ident $default:ident $strategy:tt $from_str:tt
+            [$display:ident $debug:ident $hex:ident] $($state:tt)*]
+        [$($meta:tt)*] [$($prim:ident)?] $vis:vis $name:ident $tag:ident,
+            $type:ty [$($extra:ty),*], $error:tt
+    } [$($out:tt)*] [$($tags:tt)*] $fields:tt $types:tt [] [] []) => {
+        $($meta)*
+        $vis enum $name {
+            $($out)*
+        }
+
+        $crate::__impl_enum_try_from! {
+            @tag_enum [$debug]
+            [$endian $err_kind $default $strategy $from_str [$display $debug $hex] $($state)*]
+            [
+                #[doc = concat!("Tags of the variants of [`", stringify!($name), "`].")]
+                $(#[repr($prim)])?
+                #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+            ]
+            [$($prim)?] $vis enum $tag { $($tags)* }, $type [$($extra),*], $error
+        }
+
+        $crate::__impl_enum_try_from! { @tag_fn $vis $name $tag $fields $types, $type }
+    };
+    (@tagged $ctx:tt $out:tt $tags:tt $fields:tt $types:tt $attrs:tt $tattrs:tt $tag:tt
+        $($rest:tt)*) => {
+        compile_error!(concat!(
+            "unsupported variant `", stringify!($($rest)*), "`, expected a unit, tuple or struct \
+             variant with a `#[tag = ...]` attribute",
+        ));
+    };
+
+    // Define the tag enum, deriving `Debug` unless it's implemented by the
+    // `debug` option.
+    (@tag_enum [no] $state:tt [$($meta:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @enum $state [$($meta)* #[derive(Debug)]] $($rest)* }
+    };
+    (@tag_enum [debug] $state:tt $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @enum $state $($rest)* }
+    };
+
+    // Methods of an enum with the `tag_enum` option, which map its variants to
+    // the variants of the tag enum with the same names.
+    (@tag_fn $vis:vis $name:ident $tag:ident [$($vname:ident $fields:tt,)*] [$($ty:ty,)*],
+        $type:ty) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Returns the tag of the variant.
+            $vis fn tag(&self) -> $tag {
+                match self {
+                    $($name::$vname { .. } => $tag::$vname,)*
+                }
+            }
+
+            /// Converts the value to the tag with `try_from` and builds the
+            /// variant with that tag, reading its fields from `reader` in the
+            /// order they're declared in.
+            $vis fn try_from_tag_and<R, E>(v: $type, reader: &mut R) -> Result<Self, E>
+            where
+                E: From<<$tag as TryFrom<$type>>::Error>,
+                $(R: $crate::ReadField<$ty, Error = E>,)*
+            {
+                let tag = <$tag as TryFrom<$type>>::try_from(v)?;
+                Ok(match tag {
+                    $($tag::$vname => $crate::__impl_enum_try_from!(
+                        @tag_read reader $name $vname $fields
+                    ),)*
+                })
+            }
+        }
+    };
+
+    // Build a variant of an enum with the `tag_enum` option, reading its fields.
+    (@tag_read $reader:ident $name:ident $vname:ident []) => {
+        $name::$vname
+    };
+    (@tag_read $reader:ident $name:ident $vname:ident [($($ty:ty),*)]) => {
+        $name::$vname($($crate::ReadField::<$ty>::read_field($reader)?),*)
+    };
+    (@tag_read $reader:ident $name:ident $vname:ident [{ $($field:ident: $ty:ty),* }]) => {
+        $name::$vname { $($field: $crate::ReadField::<$ty>::read_field($reader)?),* }
+    };
+
+    // Parse the options of a variant into
+    // `{ [KIND] [(ALIAS),...] [(DEPRECATED_ALIAS),...] [RENAME] [NAME_ALIAS,...] }`
+    // and return to `@variants`. The aliases are wrapped in parentheses, so the
+    // groups can be compared with `[]`.
+    (@vopts $state:tt $vopts:tt [, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @vopts $state $vopts [$($opts)*] $($rest)* }
+    };
+    (@vopts $state:tt { [] $($vopts:tt)* } [other, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts $state { [other] $($vopts)* } [$($opts)*] $($rest)*
+        }
+    };
+    (@vopts $state:tt { [] $($vopts:tt)* } [default, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts $state { [default] $($vopts)* } [$($opts)*] $($rest)*
+        }
+    };
+    (@vopts $state:tt { $kind:tt [$($aliases:tt)*] $deprecated:tt $rename:tt $names:tt }
+        [alias = $alias:expr, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts $state { $kind [$($aliases)* ($alias),] $deprecated $rename $names }
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@vopts $state:tt { $kind:tt $aliases:tt [$($deprecated:tt)*] $rename:tt $names:tt }
+        [deprecated_alias = $alias:expr, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts $state { $kind $aliases [$($deprecated)* ($alias),] $rename $names }
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@vopts $state:tt { $kind:tt $aliases:tt $deprecated:tt [] $names:tt }
+        [rename = $rename:literal, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts $state { $kind $aliases $deprecated [$rename] $names } [$($opts)*] $($rest)*
+        }
+    };
+    (@vopts $state:tt { $kind:tt $aliases:tt $deprecated:tt $rename:tt [$($names:tt)*] }
+        [name_alias = $name:literal, $($opts:tt)*] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @vopts $state { $kind $aliases $deprecated $rename [$($names)* $name,] }
+            [$($opts)*] $($rest)*
+        }
+    };
+    (@vopts [$($state:tt)*] $vopts:tt [] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! { @variants $($state)* $vopts $($rest)* }
+    };
+    (@vopts $state:tt $vopts:tt [$($opts:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("invalid `try_from` variant options: ", stringify!($($opts)*)));
+    };
+
+    // A unit variant with a range of values gets the start of the range as the
+    // discriminant, which keeps the following implicit discriminants apart from
+    // the range.
+    (@range $ctx:tt [$($out:tt)*] [$($discr:tt)*] $units:tt [$($uranges:tt)*] $tranges:tt
+        $fallback:tt [$($vattrs:tt)*] $vname:ident [$start:expr, $end:expr] $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [$($out)* $($vattrs)* $vname = $start,] [$($discr)* $vname = $start,]
+            $units [$($uranges)* $vname [$start, $end],] $tranges $fallback [] { [] [] [] [] [] }
+            $($rest)*
+        }
+    };
+    (@range $ctx:tt [$($out:tt)*] [$($discr:tt)*] $units:tt $uranges:tt [$($tranges:tt)*]
+        $fallback:tt [$($vattrs:tt)*] $vname:ident($($fields:tt)*) [$start:expr, $end:expr]
+        $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @variants $ctx
+            [$($out)* $($vattrs)* $vname($($fields)*),] [$($discr)* $vname,]
+            $units $uranges [$($tranges)* $vname [$start, $end],] $fallback [] { [] [] [] [] [] }
+            $($rest)*
+        }
+    };
+
+    // Implement the conversions for an already defined enum. Used directly by
+    // the `TryFromRepr` derive macro. The variants are given as:
+    //
+    // * `[PRIMITIVE]` - the primitive type from `repr`, if any.
+    // * `{ VARIANT [= DISCRIMINANT], ... }` - all variants in the order of
+    //   definition, with the same discriminants as in the enum.
+    // * `{ VARIANT [(ALIAS), ...] [(DEPRECATED_ALIAS), ...] [RENAME] [NAME_ALIAS, ...], ... }`
+    //   - unit variants matching their discriminant and aliases, with their
+    //   names for `FromStr`.
+    // * `{ VARIANT [START, END], ... }` - unit variants matching a range.
+    // * `{ VARIANT [START, END], ... }` - tuple variants holding a value from a
+    //   range.
+    // * `[FALLBACK]` - `other VARIANT` or `default VARIANT`, if any.
+    //
+    // The endian is followed by the lookup strategy (`auto`, `match`, `table` or
+    // `binary_search`), the case of the names for `FromStr` in brackets (empty
+    // if `FromStr` is not implemented) and optionally by a function called with
+    // the value and the variant when a deprecated alias is converted.
+    //
+    // The error is either `{ ERROR_TYPE, ERROR }` or `{}` for `TryFromReprError`,
+    // or for no error if there is a fallback variant. `ERROR` is a value, a
+    // closure or a function prefixed with `@fn`.
+    (@impl $endian:tt $vis:vis $name:ident $prim:tt $discr:tt $units:tt $uranges:tt $tranges:tt
+        [$kind:ident $fallback:ident], $type:ty [$($extra:ty),*], { $($error:tt)+ }) => {
+        compile_error!(concat!(
+            "the error arguments can't be used together with the `", stringify!($kind),
+            "` variant `", stringify!($fallback), "`",
+        ));
+    };
+    (@impl $endian:tt $vis:vis $name:ident $prim:tt $discr:tt $units:tt $uranges:tt $tranges:tt
+        [other $other:ident], $type:ty [$($extra:ty),+], {}) => {
+        compile_error!(concat!(
+            "the `other` variant `", stringify!($other), "` can't hold values of other types \
+             than `", stringify!($type), "`, convert from a single type",
+        ));
+    };
+    (@impl [$endian:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident $bytemuck:ident $consts:ident
+        $($hook:path)?] $vis:vis $name:ident
+        $prim:tt $discr:tt
+        $units:tt $uranges:tt
+        $tranges:tt [other $other:ident], $type:ty [], {}) => {
+        const _: () = {
+            $crate::__impl_enum_try_from! {
+                @from_repr $strategy [$($hook)?] $prim $name $discr $units $uranges $tranges, $type
+            }
+
+            impl From<$type> for $name {
+                fn from(raw: $type) -> Self {
+                    let v = $crate::__impl_enum_try_from!(@from_endian $endian, $type, raw);
+                    match from_repr(v) {
+                        Some(v) => v,
+                        None => $name::$other(v),
+                    }
+                }
+            }
+
+            $crate::__impl_enum_try_from! {
+                @into [$endian] $name $units $uranges $tranges [$other], $type
+            }
+            $crate::__impl_enum_try_from! { @enum_repr [$endian] $name, $type }
+            $crate::__impl_enum_try_from! { @repr_fn [$endian] $vis $name, $type }
+            $crate::__impl_enum_try_from! { @consts [$consts] $vis $name $units $uranges, $type }
+            $crate::__impl_enum_try_from! { @from_str $from_str $name $units $uranges }
+            $crate::__impl_enum_try_from! {
+                @fmt $fmt $from_str $name $units $uranges $tranges [$other], $type
+            }
+            $crate::__impl_enum_try_from_serde! {
+                [$serde] $from_str $name $units $uranges $tranges [$other], $type, $endian
+            }
+            $crate::__impl_enum_try_from_bytemuck! {
+                [$bytemuck] $prim $name $units $uranges $tranges [$other]
+            }
+        };
+
+        $crate::__impl_enum_try_from! { @endian_fn $endian, $vis $name, $type }
+        $crate::__impl_enum_try_from! { @bytes [from] $endian, $vis $name, $type }
+    };
+    (@impl [$endian:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident $bytemuck:ident $consts:ident
+        $($hook:path)?] $vis:vis $name:ident
+        $prim:tt $discr:tt
+        $units:tt $uranges:tt
+        $tranges:tt [default $default:ident], $type:ty [$($extra:ty),*], {}) => {
+        const _: () = {
+            $crate::__impl_enum_try_from! {
+                @from_repr $strategy [$($hook)?] $prim $name $discr $units $uranges $tranges, $type
+            }
+
+            impl From<$type> for $name {
+                fn from(raw: $type) -> Self {
+                    let v = $crate::__impl_enum_try_from!(@from_endian $endian, $type, raw);
+                    from_repr(v).unwrap_or($name::$default)
+                }
+            }
+
+            $crate::__impl_enum_try_from! {
+                @types [$endian] $name $units $uranges $tranges, $type, [$($extra),*], [default $default]
+            }
+
+            $crate::__impl_enum_try_from! {
+                @into [$endian] $name $units $uranges $tranges [], $type
+            }
+            $crate::__impl_enum_try_from! { @enum_repr [$endian] $name, $type }
+            $crate::__impl_enum_try_from! { @repr_fn [$endian] $vis $name, $type }
+            $crate::__impl_enum_try_from! { @consts [$consts] $vis $name $units $uranges, $type }
+            $crate::__impl_enum_try_from! { @from_str $from_str $name $units $uranges }
+            $crate::__impl_enum_try_from! {
+                @fmt $fmt $from_str $name $units $uranges $tranges [], $type
+            }
+            $crate::__impl_enum_try_from_serde! {
+                [$serde] $from_str $name $units $uranges $tranges [], $type, $endian
+            }
+            $crate::__impl_enum_try_from_bytemuck! {
+                [$bytemuck] $prim $name $units $uranges $tranges []
+            }
+        };
+
+        $crate::__impl_enum_try_from! { @endian_fn $endian, $vis $name, $type }
+        $crate::__impl_enum_try_from! { @bytes [from] $endian, $vis $name, $type }
+    };
+    (@impl [$endian:ident $strategy:tt $from_str:tt $fmt:tt $serde:ident $bytemuck:ident $consts:ident
+        $($hook:path)?] $vis:vis $name:ident
+        $prim:tt $discr:tt
+        $units:tt $uranges:tt
+        $tranges:tt [], $type:ty [$($extra:ty),*], { $err_ty:ty, $($err:tt)* }) => {
+        const _: () = {
+            $crate::__impl_enum_try_from! {
+                @from_repr $strategy [$($hook)?] $prim $name $discr $units $uranges $tranges, $type
+            }
+
+            impl TryFrom<$type> for $name {
+                type Error = $err_ty;
+
+                fn try_from(raw: $type) -> Result<Self, Self::Error> {
+                    let v = $crate::__impl_enum_try_from!(@from_endian $endian, $type, raw);
+                    match from_repr(v) {
+                        Some(v) => Ok(v),
+                        None => Err($crate::__impl_enum_try_from!(
+                            @err [$($err)*] $name $units $uranges $tranges, $type, raw, v
+                        )),
+                    }
+                }
+            }
+
+            $crate::__impl_enum_try_from! {
+                @types [$endian] $name $units $uranges $tranges, $type, [$($extra),*],
+                { $err_ty, $($err)* }
+            }
+
+            $crate::__impl_enum_try_from! {
+                @into [$endian] $name $units $uranges $tranges [], $type
+            }
+            $crate::__impl_enum_try_from! { @enum_repr [$endian] $name, $type }
+            $crate::__impl_enum_try_from! { @repr_fn [$endian] $vis $name, $type }
+            $crate::__impl_enum_try_from! { @consts [$consts] $vis $name $units $uranges, $type }
+            $crate::__impl_enum_try_from! { @from_str $from_str $name $units $uranges }
+            $crate::__impl_enum_try_from! {
+                @fmt $fmt $from_str $name $units $uranges $tranges [], $type
+            }
+            $crate::__impl_enum_try_from_serde! {
+                [$serde] $from_str $name $units $uranges $tranges [], $type, $endian
+            }
+            $crate::__impl_enum_try_from_bytemuck! {
+                [$bytemuck] $prim $name $units $uranges $tranges []
+            }
+        };
+
+        $crate::__impl_enum_try_from! { @endian_fn $endian, $vis $name, $type }
+        $crate::__impl_enum_try_from! { @bytes [try] $endian, $vis $name, $type }
+    };
+    (@impl $endian:tt $vis:vis $name:ident $prim:tt $discr:tt $units:tt $uranges:tt $tranges:tt
+        [], $type:ty [$($extra:ty),*], {}) => {
+        $crate::__impl_enum_try_from! {
+            @impl $endian $vis $name $prim $discr $units $uranges $tranges [], $type [$($extra),*],
+            { $crate::TryFromReprError<$type>, @repr }
+        }
+    };
+
+    // Define the flag enum and the flag set, implementing the conversions for
+    // both of them.
+    (@flags [$endian:ident $strategy:tt] [$(#[$meta:meta])*] [$($prim:ident)?] $vis:vis enum $name:ident {
+        $($(#[$vmeta:meta])* $vname:ident = $val:expr),* $(,)?
+    } $set:ident, $type:ty) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$vmeta])* $vname = $val,)*
+        }
+
+        $crate::__impl_enum_try_from! {
+            @impl [$endian $strategy [] [no no no] no no no] $vis $name [$($prim)?] { $($vname = $val,)* } { $($vname [] [] [] [],)* }
+            {} {} [], $type [], {}
+        }
+
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $set($type);
+
+        const _: () = {
+            $crate::__impl_enum_try_from! {
+                @from_repr $strategy [] [$($prim)?] $name { $($vname = $val,)* } { $($vname [] [] [] [],)* }
+                {} {}, $type
+            }
+
+            // Reject variants which are not a single bit.
+            const _: () = {
+                $(
+                    assert!(
+                        (Discriminant::$vname as $type).count_ones() == 1,
+                        concat!(
+                            "discriminant of `", stringify!($name), "::", stringify!($vname),
+                            "` is not a single bit",
+                        ),
+                    );
+                )*
+            };
+
+            const ALL: $type = 0 $(| Discriminant::$vname as $type)*;
+            const NAMES: &[(&str, $type)] = &[$((stringify!($vname), Discriminant::$vname as $type),)*];
+
+            // Not all methods have to be used when the set is private.
+            #[allow(dead_code)]
+            impl $set {
+                /// Returns the set without any flags.
+                $vis const fn empty() -> Self {
+                    Self(0)
+                }
+
+                /// Returns the set with all flags.
+                $vis const fn all() -> Self {
+                    Self(ALL)
+                }
+
+                /// Returns the bits of the set in native byte order.
+                $vis const fn bits(&self) -> $type {
+                    self.0
+                }
+
+                /// Converts the value to the set like `try_from`, but drops
+                /// the unknown bits instead of failing.
+                $vis const fn from_bits_truncate(raw: $type) -> Self {
+                    Self($crate::__impl_enum_try_from!(@from_endian $endian, $type, raw) & ALL)
+                }
+
+                /// Returns `true` if no flags are set.
+                $vis const fn is_empty(&self) -> bool {
+                    self.0 == 0
+                }
+
+                /// Returns `true` if the flag is set.
+                $vis const fn contains(&self, flag: $name) -> bool {
+                    self.0 & flag as $type != 0
+                }
+
+                /// Sets the flag.
+                $vis fn insert(&mut self, flag: $name) {
+                    self.0 |= flag as $type;
+                }
+
+                /// Clears the flag.
+                $vis fn remove(&mut self, flag: $name) {
+                    self.0 &= !(flag as $type);
+                }
+
+                /// Returns an iterator over the set flags, from the lowest bit.
+                $vis fn iter(&self) -> impl Iterator<Item = $name> {
+                    let bits = self.0;
+                    (0..<$type>::BITS).filter_map(move |i| match bits & (1 << i) {
+                        0 => None,
+                        bit => from_repr(bit),
+                    })
+                }
+            }
+
+            impl From<$name> for $set {
+                fn from(flag: $name) -> Self {
+                    Self(flag as $type)
+                }
+            }
+
+            impl FromIterator<$name> for $set {
+                fn from_iter<I: IntoIterator<Item = $name>>(iter: I) -> Self {
+                    let mut set = Self::empty();
+                    for flag in iter {
+                        set.insert(flag);
+                    }
+                    set
+                }
+            }
+
+            impl core::ops::BitOr for $name {
+                type Output = $set;
+
+                fn bitor(self, rhs: Self) -> $set {
+                    $set(self as $type | rhs as $type)
+                }
+            }
+
+            impl core::ops::BitOr for $set {
+                type Output = Self;
+
+                fn bitor(self, rhs: Self) -> Self {
+                    Self(self.0 | rhs.0)
+                }
+            }
+
+            impl core::ops::BitOr<$name> for $set {
+                type Output = Self;
+
+                fn bitor(self, rhs: $name) -> Self {
+                    Self(self.0 | rhs as $type)
+                }
+            }
+
+            impl core::ops::BitOrAssign for $set {
+                fn bitor_assign(&mut self, rhs: Self) {
+                    self.0 |= rhs.0;
+                }
+            }
+
+            impl core::ops::BitOrAssign<$name> for $set {
+                fn bitor_assign(&mut self, rhs: $name) {
+                    self.insert(rhs);
+                }
+            }
+
+            impl core::ops::BitAnd for $set {
+                type Output = Self;
+
+                fn bitand(self, rhs: Self) -> Self {
+                    Self(self.0 & rhs.0)
+                }
+            }
+
+            impl core::ops::BitAnd<$name> for $set {
+                type Output = Self;
+
+                fn bitand(self, rhs: $name) -> Self {
+                    Self(self.0 & rhs as $type)
+                }
+            }
+
+            impl core::ops::BitAndAssign for $set {
+                fn bitand_assign(&mut self, rhs: Self) {
+                    self.0 &= rhs.0;
+                }
+            }
+
+            impl core::fmt::Debug for $set {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(concat!(stringify!($set), "("))?;
+                    let mut separator = "";
+                    for (name, bit) in NAMES {
+                        if self.0 & bit != 0 {
+                            f.write_str(separator)?;
+                            f.write_str(name)?;
+                            separator = " | ";
+                        }
+                    }
+                    f.write_str(")")
+                }
+            }
+
+            impl TryFrom<$type> for $set {
+                type Error = $crate::TryFromFlagsError<$type>;
+
+                fn try_from(raw: $type) -> Result<Self, Self::Error> {
+                    let v = $crate::__impl_enum_try_from!(@from_endian $endian, $type, raw);
+                    match v & !ALL {
+                        0 => Ok(Self(v)),
+                        unknown_bits => Err($crate::TryFromFlagsError {
+                            value: v,
+                            unknown_bits,
+                            flags_name: stringify!($set),
+                        }),
+                    }
+                }
+            }
+
+            impl From<&$set> for $type {
+                fn from(v: &$set) -> Self {
+                    let v = v.0;
+                    $crate::__impl_enum_try_from!(@to_endian $endian, $type, v)
+                }
+            }
+
+            impl From<$set> for $type {
+                fn from(v: $set) -> Self {
+                    <$type>::from(&v)
+                }
+            }
+        };
+
+        $crate::__impl_enum_try_from! { @endian_fn $endian, $vis $set, $type }
+    };
+    (@flags $endian:tt $meta:tt $prim:tt $vis:vis enum $name:ident $($rest:tt)*) => {
+        compile_error!(concat!(
+            "the variants of `", stringify!($name), "` must be unit variants with a discriminant",
+        ));
+    };
+
+    (@default_impl [no] $name:ident $fallback:tt) => {};
+    (@default_impl [impl] $name:ident [default $default:ident]) => {
+        impl Default for $name {
+            fn default() -> Self {
+                $name::$default
+            }
+        }
+    };
+    (@default_impl [impl] $name:ident $fallback:tt) => {
+        compile_error!("the `impl_default` option requires a variant with `#[try_from(default)]`");
+    };
+
+    // Type of the discriminants, which is `isize` without a primitive `repr`.
+    (@discr_type []) => { isize };
+    (@discr_type [$prim:ident]) => { $prim };
+
+    // Define the `Discriminant` enum and the `from_repr` function converting a
+    // native byte order value to the enum, and check the values at compile time.
+    (@from_repr $strategy:tt $hook:tt [$($prim:ident)?] $name:ident {
+        $($dname:ident $(= $dval:expr)?,)*
+    } {
+        $($vname:ident [$(($alias:expr),)*] [$(($deprecated:expr),)*] $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {
+        $($tname:ident [$tstart:expr, $tend:expr],)*
+    }, $type:ty) => {
+        // Fieldless copy of the enum, which provides the discriminants of the
+        // unit variants also when the enum has variants with fields.
+        #[allow(dead_code)]
+        #[derive(Clone, Copy)]
+        $(#[repr($prim)])?
+        enum Discriminant {
+            $($dname $(= $dval)?,)*
+        }
+
+        // Reject discriminants which would be truncated or change the sign when
+        // cast to the type, which could also make them collide. The signs are
+        // compared separately, as 128-bit values can change the sign without
+        // changing the bits.
+        const _: () = {
+            // Unused if there are no unit variants.
+            #[allow(dead_code)]
+            type Repr = $crate::__impl_enum_try_from!(@discr_type [$($prim)?]);
+
+            $(
+                let cast = Discriminant::$vname as $type;
+                assert!(
+                    Discriminant::$vname as i128 == cast as i128
+                        && (<Repr>::MIN != 0 && (Discriminant::$vname as i128) < 0)
+                            == (<$type>::MIN != 0 && (cast as i128) < 0),
+                    concat!(
+                        "discriminant of `", stringify!($name), "::", stringify!($vname),
+                        "` doesn't fit in `", stringify!($type), "`",
+                    ),
+                );
+            )*
+        };
+
+        // Reject empty ranges, and ranges and aliases overlapping with other
+        // variants.
+        const _: () = {
+            // Unused if there are no ranges and aliases.
+            #[allow(dead_code)]
+            const VALUES: &[(i128, i128)] = &[
+                $((Discriminant::$vname as i128, Discriminant::$vname as i128),)*
+                $($({
+                    let alias: $type = $alias;
+                    (alias as i128, alias as i128)
+                },)*)*
+                $($({
+                    let alias: $type = $deprecated;
+                    (alias as i128, alias as i128)
+                },)*)*
+                $({
+                    let (start, end): ($type, $type) = ($ustart, $uend);
+                    (start as i128, end as i128)
+                },)*
+                $({
+                    let (start, end): ($type, $type) = ($tstart, $tend);
+                    (start as i128, end as i128)
+                },)*
+            ];
+            $($({
+                let alias: $type = $alias;
+                assert!(
+                    $crate::__count_overlapping(VALUES, (alias as i128, alias as i128)) == 1,
+                    concat!(
+                        "alias ", stringify!($alias), " of `", stringify!($name), "::",
+                        stringify!($vname), "` overlaps with another variant",
+                    ),
+                );
+            })*)*
+            $($({
+                let alias: $type = $deprecated;
+                assert!(
+                    $crate::__count_overlapping(VALUES, (alias as i128, alias as i128)) == 1,
+                    concat!(
+                        "alias ", stringify!($deprecated), " of `", stringify!($name), "::",
+                        stringify!($vname), "` overlaps with another variant",
+                    ),
+                );
+            })*)*
+            $({
+                let (start, end): ($type, $type) = ($ustart, $uend);
+                assert!(
+                    start <= end,
+                    concat!("range of `", stringify!($name), "::", stringify!($uname), "` is empty"),
+                );
+                assert!(
+                    $crate::__count_overlapping(VALUES, (start as i128, end as i128)) == 1,
+                    concat!(
+                        "range of `", stringify!($name), "::", stringify!($uname),
+                        "` overlaps with another variant",
+                    ),
+                );
+            })*
+            $({
+                let (start, end): ($type, $type) = ($tstart, $tend);
+                assert!(
+                    start <= end,
+                    concat!("range of `", stringify!($name), "::", stringify!($tname), "` is empty"),
+                );
+                assert!(
+                    $crate::__count_overlapping(VALUES, (start as i128, end as i128)) == 1,
+                    concat!(
+                        "range of `", stringify!($name), "::", stringify!($tname),
+                        "` overlaps with another variant",
+                    ),
+                );
+            })*
+        };
+
+        // Convert a native byte order value to the enum, without calling the
+        // hook, so it can be used in const contexts.
+        const fn lookup(v: $type) -> Option<$name> {
+            let variant = $crate::__impl_enum_try_from!(
+                @lookup [$strategy] $name { $($vname,)* }, $type, v
+            );
+            if variant.is_some() {
+                return variant;
+            }
+            // Constants with the bounds of the ranges, which can be used as
+            // patterns. Unused if there are no ranges.
+            #[allow(dead_code)]
+            struct Start;
+            #[allow(dead_code)]
+            struct End;
+            #[allow(non_upper_case_globals)]
+            impl Start {
+                $(const $uname: $type = $ustart;)*
+                $(const $tname: $type = $tstart;)*
+            }
+            #[allow(non_upper_case_globals)]
+            impl End {
+                $(const $uname: $type = $uend;)*
+                $(const $tname: $type = $tend;)*
+            }
+            // The ranges can cover all the values.
+            #[allow(unreachable_patterns)]
+            match v {
+                $($(x if x == $alias => Some($name::$vname),)*)*
+                $($(x if x == $deprecated => Some($name::$vname),)*)*
+                $(Start::$uname..=End::$uname => Some($name::$uname),)*
+                $(x @ Start::$tname..=End::$tname => Some($name::$tname(x)),)*
+                _ => None,
+            }
+        }
+
+        fn from_repr(v: $type) -> Option<$name> {
+            let variant = lookup(v);
+            $crate::__impl_enum_try_from! {
+                @hook $hook v $name { $($vname [$($deprecated),*])* }
+            }
+            variant
+        }
+    };
+
+    // Define the `ALL`, `VALUES`, `COUNT`, `MIN` and `MAX` associated constants
+    // with the unit variants sorted by their discriminants, if the `consts`
+    // option is provided.
+    (@consts [no] $($rest:tt)*) => {};
+    (@consts [consts] $vis:vis $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    }, $type:ty) => {
+        // Unused if the enum is private and the constants aren't used.
+        #[allow(dead_code)]
+        const _: () = {
+            // Position of each variant in `DISCRIMINANTS`, and in the arrays
+            // before sorting.
+            enum Index {
+                $($vname,)*
+                $($uname,)*
+            }
+
+            // The unit variants with a range are converted to the start of the
+            // range, which isn't necessarily their discriminant with the derive
+            // macro.
+            const DISCRIMINANTS: &[$type] = &[
+                $(Discriminant::$vname as $type,)*
+                $($ustart,)*
+            ];
+            const COUNT: usize = DISCRIMINANTS.len();
+
+            // Position of each variant after sorting.
+            const RANKS: [usize; COUNT] = {
+                let mut ranks = [0; COUNT];
+                let mut i = 0;
+                while i < COUNT {
+                    let mut j = 0;
+                    while j < COUNT {
+                        if DISCRIMINANTS[j] < DISCRIMINANTS[i] {
+                            ranks[i] += 1;
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                ranks
+            };
+
+            const fn sorted(rank: usize) -> $name {
+                $(
+                    if RANKS[Index::$vname as usize] == rank {
+                        return $name::$vname;
+                    }
+                )*
+                $(
+                    if RANKS[Index::$uname as usize] == rank {
+                        return $name::$uname;
+                    }
+                )*
+                unreachable!()
+            }
+
+            impl $name {
+                /// All unit variants, sorted by their discriminants.
+                $vis const ALL: [Self; COUNT] = [
+                    $(sorted(Index::$vname as usize),)*
+                    $(sorted(Index::$uname as usize),)*
+                ];
+                /// Discriminants of the unit variants, sorted in ascending order.
+                $vis const VALUES: [$type; COUNT] = {
+                    let mut values = [0; COUNT];
+                    let mut i = 0;
+                    while i < COUNT {
+                        values[RANKS[i]] = DISCRIMINANTS[i];
+                        i += 1;
+                    }
+                    values
+                };
+                /// Number of the unit variants.
+                $vis const COUNT: usize = COUNT;
+                /// The lowest discriminant of the unit variants.
+                $vis const MIN: $type = {
+                    assert!(COUNT > 0, concat!("`", stringify!($name), "` has no unit variants"));
+                    Self::VALUES[0]
+                };
+                /// The highest discriminant of the unit variants.
+                $vis const MAX: $type = {
+                    assert!(COUNT > 0, concat!("`", stringify!($name), "` has no unit variants"));
+                    Self::VALUES[COUNT - 1]
+                };
+            }
+        };
+    };
+
+    // Implement `FromStr` and `TryFrom<&str>` converting the names of the unit
+    // variants, if the `from_str` option is provided. The names can be renamed
+    // and have aliases, which are not converted to the case of the option.
+    (@from_str [] $name:ident $units:tt $uranges:tt) => {};
+    (@from_str [$case:ident] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt [$($rename:literal)?] [$($names:literal,)*],)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    }) => {
+        // Reject names matching more than one variant.
+        const _: () = {
+            const CASE: $crate::__NameCase = $crate::__NameCase::$case;
+            // Unused if there are no unit variants.
+            #[allow(dead_code)]
+            const NAMES: &[(&str, $crate::__NameCase)] = &[
+                $($crate::__impl_enum_try_from!(@name CASE $vname [$($rename)?]), $((
+                    $names, CASE.literal()
+                ),)*)*
+                $((stringify!($uname), CASE),)*
+            ];
+            $({
+                let (name, case) = $crate::__impl_enum_try_from!(@name CASE $vname [$($rename)?]);
+                assert!(
+                    $crate::__count_names(NAMES, name, case) == 1,
+                    concat!(
+                        "the name of `", stringify!($name), "::", stringify!($vname),
+                        "` matches another variant",
+                    ),
+                );
+                $(assert!(
+                    $crate::__count_names(NAMES, $names, CASE.literal()) == 1,
+                    concat!(
+                        "name alias ", stringify!($names), " of `", stringify!($name), "::",
+                        stringify!($vname), "` matches another variant",
+                    ),
+                );)*
+            })*
+            $(assert!(
+                $crate::__count_names(NAMES, stringify!($uname), CASE) == 1,
+                concat!(
+                    "the name of `", stringify!($name), "::", stringify!($uname),
+                    "` matches another variant",
+                ),
+            );)*
+        };
+
+        impl core::str::FromStr for $name {
+            type Err = $crate::ParseNameError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                const CASE: $crate::__NameCase = $crate::__NameCase::$case;
+                $(
+                    let (name, case) =
+                        $crate::__impl_enum_try_from!(@name CASE $vname [$($rename)?]);
+                    if $crate::__name_eq(name, case, s, CASE.literal())
+                        $(|| $crate::__name_eq($names, CASE.literal(), s, CASE.literal()))*
+                    {
+                        return Ok($name::$vname);
+                    }
+                )*
+                $(
+                    if $crate::__name_eq(stringify!($uname), CASE, s, CASE.literal()) {
+                        return Ok($name::$uname);
+                    }
+                )*
+                Err($crate::ParseNameError {
+                    enum_name: stringify!($name),
+                })
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = $crate::ParseNameError;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+    };
+
+    // The name of a unit variant and its case.
+    (@name $case:ident $vname:ident []) => {
+        (stringify!($vname), $case)
+    };
+    (@name $case:ident $vname:ident [$rename:literal]) => {
+        ($rename, $case.literal())
+    };
+
+    // Implement `Display` with the names of the variants, `Debug` with the
+    // names and the values, and `LowerHex` and `UpperHex` with the values, if
+    // the options are provided.
+    (@fmt [no no no] [] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt [] [],)*
+    } $($rest:tt)*) => {};
+    (@fmt [no no no] [$case:ident] $($rest:tt)*) => {};
+    (@fmt [no $debug:ident $hex:ident] [] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt [] [],)*
+    } $($rest:tt)*) => {
+        $crate::__impl_enum_try_from! {
+            @fmt [no $debug $hex] [Exact] $name { $($vname $aliases $deprecated [] [],)* } $($rest)*
+        }
+    };
+    (@fmt [no $debug:ident $hex:ident] [] $name:ident $units:tt $($rest:tt)*) => {
+        compile_error!(
+            "the `rename` and `name_alias` variant options require the `from_str` or `display` option"
+        );
+    };
+    (@fmt [$display:ident $debug:ident $hex:ident] [$($case:ident)?] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt [$($rename:literal)?] $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {
+        $($tname:ident [$tstart:expr, $tend:expr],)*
+    } [$($other:ident)?], $type:ty) => {
+        // Name of the variant, converted to the case of `from_str`.
+        #[allow(dead_code)]
+        fn name(v: &$name) -> &'static str {
+            const CASE: $crate::__NameCase = $crate::__impl_enum_try_from!(@case $($case)?);
+            match v {
+                $($name::$vname => $crate::__impl_enum_try_from!(@name_str CASE $vname [$($rename)?]),)*
+                $($name::$uname => $crate::__impl_enum_try_from!(@name_str CASE $uname []),)*
+                $($name::$tname(_) => $crate::__impl_enum_try_from!(@name_str CASE $tname []),)*
+                $($name::$other(_) => $crate::__impl_enum_try_from!(@name_str CASE $other []),)?
+            }
+        }
+
+        $crate::__impl_enum_try_from! { @fmt_impl $display $name, $type }
+        $crate::__impl_enum_try_from! { @fmt_impl $debug $name, $type }
+        $crate::__impl_enum_try_from! { @fmt_impl $hex $name, $type }
+    };
+
+    (@fmt_impl no $name:ident, $type:ty) => {};
+    (@fmt_impl display $name:ident, $type:ty) => {
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.pad(name(self))
+            }
+        }
+    };
+    (@fmt_impl debug $name:ident, $type:ty) => {
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                // Pad the value to the width of the type, with `0x`.
+                let width = 2 + 2 * core::mem::size_of::<$type>();
+                write!(f, "{}({:#0width$x})", name(self), to_repr(self))
+            }
+        }
+    };
+    (@fmt_impl hex $name:ident, $type:ty) => {
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&to_repr(self), f)
+            }
+        }
+
+        impl core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperHex::fmt(&to_repr(self), f)
+            }
+        }
+    };
+
+    (@case) => {
+        $crate::__NameCase::Exact
+    };
+    (@case $case:ident) => {
+        $crate::__NameCase::$case
+    };
+
+    // The name of a variant as a `&'static str`, converted to the case at
+    // compile time unless it's renamed.
+    (@name_str $case:ident $vname:ident [$rename:literal]) => {
+        $rename
+    };
+    (@name_str $case:ident $vname:ident []) => {{
+        const NAME: &str = stringify!($vname);
+        const LEN: usize = $crate::__name_len(NAME, $case);
+        const BYTES: [u8; LEN] = $crate::__name_bytes(NAME, $case);
+        const CONVERTED: &str = match core::str::from_utf8(&BYTES) {
+            Ok(name) => name,
+            Err(_) => panic!("invalid name"),
+        };
+        CONVERTED
+    }};
+
+    // Look up the unit variant with the discriminant `$v`, using the literal
+    // patterns of a `match`, a table indexed by the discriminant or a binary
+    // search. `auto` uses the table when it has at most twice as many entries
+    // as there are variants, and the `match` otherwise.
+    (@lookup [match] $name:ident { $($vname:ident,)* }, $type:ty, $v:ident) => {{
+        // Constants named after the variants, which can be used as patterns.
+        struct Values;
+        #[allow(non_upper_case_globals)]
+        impl Values {
+            $(const $vname: $type = Discriminant::$vname as $type;)*
+        }
+        match $v {
+            $(Values::$vname => Some($name::$vname),)*
+            _ => None,
+        }
+    }};
+    (@lookup [table] $name:ident $units:tt, $type:ty, $v:ident) => {{
+        $crate::__impl_enum_try_from! { @table [table] $name $units, $type }
+        $crate::__impl_enum_try_from!(@table_lookup $name $units, $v)
+    }};
+    (@lookup [binary_search] $name:ident { $($vname:ident,)* }, $type:ty, $v:ident) => {{
+        const KEYS: &[($type, Discriminant)] =
+            &[$((Discriminant::$vname as $type, Discriminant::$vname),)*];
+        const LEN: usize = KEYS.len();
+        // Sort the discriminants with an insertion sort at compile time.
+        const SORTED: ([$type; LEN], [Option<Discriminant>; LEN]) = {
+            let mut values = [0; LEN];
+            let mut variants = [None; LEN];
+            let mut i = 0;
+            while i < LEN {
+                let (value, variant) = KEYS[i];
+                let mut j = i;
+                while j > 0 && values[j - 1] > value {
+                    values[j] = values[j - 1];
+                    variants[j] = variants[j - 1];
+                    j -= 1;
+                }
+                values[j] = value;
+                variants[j] = Some(variant);
+                i += 1;
+            }
+            (values, variants)
+        };
+        static VALUES: [$type; LEN] = SORTED.0;
+        static VARIANTS: [Option<Discriminant>; LEN] = SORTED.1;
+        let mut low = 0;
+        let mut high = LEN;
+        let mut variant = None;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if VALUES[mid] < $v {
+                low = mid + 1;
+            } else if VALUES[mid] > $v {
+                high = mid;
+            } else {
+                variant = VARIANTS[mid];
+                break;
+            }
+        }
+        $crate::__impl_enum_try_from!(@unit $name { $($vname,)* } variant)
+    }};
+    (@lookup [auto] $name:ident $units:tt, $type:ty, $v:ident) => {{
+        $crate::__impl_enum_try_from! { @table [auto] $name $units, $type }
+        if TABLE_LEN > 0 {
+            $crate::__impl_enum_try_from!(@table_lookup $name $units, $v)
+        } else {
+            $crate::__impl_enum_try_from!(@lookup [match] $name $units, $type, $v)
+        }
+    }};
+
+    // Define the table of the unit variants, indexed by the discriminant minus
+    // `TABLE_MIN`. For `auto`, the table is empty if it would be sparse.
+    (@table [$strategy:ident] $name:ident { $($vname:ident,)* }, $type:ty) => {
+        const KEYS: &[($type, Discriminant)] =
+            &[$((Discriminant::$vname as $type, Discriminant::$vname),)*];
+        const TABLE_MIN: $type = {
+            let mut min = <$type>::MAX;
+            let mut i = 0;
+            while i < KEYS.len() {
+                if KEYS[i].0 < min {
+                    min = KEYS[i].0;
+                }
+                i += 1;
+            }
+            min
+        };
+        const TABLE_MAX: $type = {
+            let mut max = <$type>::MIN;
+            let mut i = 0;
+            while i < KEYS.len() {
+                if KEYS[i].0 > max {
+                    max = KEYS[i].0;
+                }
+                i += 1;
+            }
+            max
+        };
+        // The difference wraps around in `i128`, but it's exact as `u128`, also
+        // for 128-bit types. A span of all `u128` values saturates.
+        const SPAN: u128 = if KEYS.is_empty() {
+            0
+        } else {
+            ((TABLE_MAX as i128).wrapping_sub(TABLE_MIN as i128) as u128).saturating_add(1)
+        };
+        const TABLE_LEN: usize = $crate::__impl_enum_try_from!(@table_len [$strategy] $name, KEYS.len());
+        static TABLE: [Option<Discriminant>; TABLE_LEN] = {
+            let mut table = [None; TABLE_LEN];
+            let mut i = 0;
+            while i < TABLE_LEN && i < KEYS.len() {
+                table[(KEYS[i].0 as i128).wrapping_sub(TABLE_MIN as i128) as usize] =
+                    Some(KEYS[i].1);
+                i += 1;
+            }
+            table
+        };
+    };
+    (@table_len [table] $name:ident, $len:expr) => {{
+        assert!(
+            SPAN <= 1 << 16,
+            concat!(
+                "the discriminants of `", stringify!($name),
+                "` span too many values for the `table` strategy",
+            ),
+        );
+        SPAN as usize
+    }};
+    (@table_len [auto] $name:ident, $len:expr) => {
+        if SPAN <= 2 * $len as u128 {
+            SPAN as usize
+        } else {
+            0
+        }
+    };
+    (@table_lookup $name:ident $units:tt, $v:ident) => {
+        if $v >= TABLE_MIN && $v <= TABLE_MAX {
+            let index = ($v as i128).wrapping_sub(TABLE_MIN as i128) as usize;
+            $crate::__impl_enum_try_from!(
+                @unit $name $units if index < TABLE_LEN { TABLE[index] } else { None }
+            )
+        } else {
+            None
+        }
+    };
+
+    // Convert an optional discriminant of a unit variant to the variant.
+    (@unit $name:ident { $($vname:ident,)* } $discriminant:expr) => {
+        match $discriminant {
+            $(Some(Discriminant::$vname) => Some($name::$vname),)*
+            _ => None,
+        }
+    };
+
+    // Call the hook if the value is a deprecated alias.
+    (@hook [] $v:ident $name:ident $deprecated:tt) => {};
+    (@hook [$hook:path] $v:ident $name:ident { $($vname:ident [$($deprecated:expr),*])* }) => {
+        $($(
+            if $v == $deprecated {
+                $hook($v, &$name::$vname);
+            }
+        )*)*
+    };
+
+    // The unit variants with a range are converted to the start of the range.
+    (@into [$endian:ident] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {
+        $($tname:ident [$tstart:expr, $tend:expr],)*
+    } [$($other:ident)?], $type:ty) => {
+        // Convert the enum to a native byte order value.
+        const fn to_repr(v: &$name) -> $type {
+            match *v {
+                $($name::$vname => Discriminant::$vname as $type,)*
+                $($name::$uname => $ustart,)*
+                $($name::$tname(v) => v,)*
+                $($name::$other(v) => v,)?
+            }
+        }
+
+        impl From<&$name> for $type {
+            fn from(v: &$name) -> Self {
+                let v = to_repr(v);
+                $crate::__impl_enum_try_from!(@to_endian $endian, $type, v)
+            }
+        }
+
+        impl From<$name> for $type {
+            fn from(v: $name) -> Self {
+                <$type>::from(&v)
+            }
+        }
+    };
+
+    // Const conversions from and to values in the byte order of `try_from`, in
+    // native byte order, and in the byte order of `try_from` if it's fixed.
+    (@repr_fn [$endian:ident] $vis:vis $name:ident, $type:ty) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Returns the variant matching the value, given in the byte order
+            /// of `try_from`, or `None` if it doesn't match any variant. Unlike
+            /// `try_from`, it can be used in const contexts, but it doesn't call
+            /// the `deprecated_alias_hook`.
+            $vis const fn from_repr(v: $type) -> Option<Self> {
+                lookup($crate::__impl_enum_try_from!(@from_endian $endian, $type, v))
+            }
+
+            /// Converts the variant to the value, in the byte order of
+            /// `try_from`.
+            $vis const fn repr(self) -> $type {
+                let v = to_repr(&self);
+                $crate::__impl_enum_try_from!(@to_endian $endian, $type, v)
+            }
+
+            /// Same as `from_repr`, but with the value in native byte order.
+            $vis const fn from_ne_repr(v: $type) -> Option<Self> {
+                lookup(v)
+            }
+
+            /// Same as `repr`, but converts the variant to a native byte order
+            /// value.
+            $vis const fn ne_repr(self) -> $type {
+                to_repr(&self)
+            }
+        }
+
+        $crate::__impl_enum_try_from! { @repr_endian_fn [$endian] $vis $name, $type }
+    };
+    (@repr_endian_fn [be] $vis:vis $name:ident, $type:ty) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Same as `from_repr`, but with the value in big endian.
+            $vis const fn from_be_repr(v: $type) -> Option<Self> {
+                lookup(<$type>::from_be(v))
+            }
+
+            /// Same as `repr`, but converts the variant to a big endian value.
+            $vis const fn be_repr(self) -> $type {
+                <$type>::to_be(to_repr(&self))
+            }
+        }
+    };
+    (@repr_endian_fn [le] $vis:vis $name:ident, $type:ty) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Same as `from_repr`, but with the value in little endian.
+            $vis const fn from_le_repr(v: $type) -> Option<Self> {
+                lookup(<$type>::from_le(v))
+            }
+
+            /// Same as `repr`, but converts the variant to a little endian value.
+            $vis const fn le_repr(self) -> $type {
+                <$type>::to_le(to_repr(&self))
+            }
+        }
+    };
+    (@repr_endian_fn [$endian:ident] $vis:vis $name:ident, $type:ty) => {};
+
+    (@enum_repr [$endian:ident] $name:ident, $type:ty) => {
+        impl $crate::EnumRepr for $name {
+            type Repr = $type;
+
+            fn from_repr(raw: $type) -> Option<Self> {
+                from_repr($crate::__impl_enum_try_from!(@from_endian $endian, $type, raw))
+            }
+
+            fn to_repr(&self) -> $type {
+                <$type>::from(self)
+            }
+        }
+    };
+
+    (@err [@repr] $name:ident {
+        $($vname:ident [$(($alias:expr),)*] [$(($deprecated:expr),)*] $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {
+        $($tname:ident [$tstart:expr, $tend:expr],)*
+    }, $type:ty, $raw:ident, $v:ident) => {
+        $crate::TryFromReprError {
+            value: $v,
+            enum_name: stringify!($name),
+            valid_values: {
+                const VALUES: &[$type] = &[
+                    $(Discriminant::$vname as $type, $($alias,)* $($deprecated,)*)*
+                ];
+                VALUES
+            },
+            valid_ranges: {
+                const RANGES: &[::core::ops::RangeInclusive<$type>] =
+                    &[$($ustart..=$uend,)* $($tstart..=$tend,)*];
+                RANGES
+            },
+        }
+    };
+    (@err [@fn $err:expr $(,)?] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $raw:ident, $v:ident) => {
+        ($err)($raw)
+    };
+    (@err [$(move)? |$p:pat_param| $body:expr $(,)?] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $raw:ident, $v:ident) => {
+        (|$p: $type| $body)($raw)
+    };
+    (@err [$(move)? |$p_raw:pat_param, $p:pat_param| $body:expr $(,)?] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $raw:ident, $v:ident) => {
+        (|$p_raw: $type, $p: $type| $body)($raw, $v)
+    };
+    // `pat_param` can't be followed by the type, so typed parameters are only
+    // accepted as identifiers.
+    (@err [$(move)? |$p:ident: $t:ty| $body:expr $(,)?] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $raw:ident, $v:ident) => {
+        (|$p: $t| $body)($raw)
+    };
+    (@err [$(move)? |$p_raw:ident $(: $t_raw:ty)?, $p:ident $(: $t:ty)?| $body:expr $(,)?] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $raw:ident, $v:ident) => {
+        (|$p_raw: $crate::__impl_enum_try_from!(@param_ty [$($t_raw)?] $type),
+          $p: $crate::__impl_enum_try_from!(@param_ty [$($t)?] $type)| $body)($raw, $v)
+    };
+    (@err [$err:expr $(,)?] $($rest:tt)*) => { $err };
+
+    (@param_ty [$t:ty] $type:ty) => { $t };
+    (@param_ty [] $type:ty) => { $type };
+
+    // Conversions from the additional types, which are converted to the
+    // primary type with `TryFrom` first, so values which don't fit in it are
+    // rejected instead of truncated.
+    (@types $endian:tt $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty,
+        [$($extra:ty),*], $error:tt) => {
+        $(
+            $crate::__impl_enum_try_from! {
+                @type $endian $name $units $uranges $tranges, $type, $extra, $error
+            }
+        )*
+    };
+    (@type [$endian:ident] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $extra:ty,
+        [default $default:ident]) => {
+        impl From<$extra> for $name {
+            fn from(raw: $extra) -> Self {
+                let v = $crate::__impl_enum_try_from!(@from_endian $endian, $extra, raw);
+                <$type as TryFrom<$extra>>::try_from(v)
+                    .ok()
+                    .and_then(from_repr)
+                    .unwrap_or($name::$default)
+            }
+        }
+    };
+    (@type [$endian:ident] $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $extra:ty,
+        { $err_ty:ty, $($err:tt)* }) => {
+        impl TryFrom<$extra> for $name {
+            type Error = $crate::__impl_enum_try_from!(@type_err_ty [$($err)*] $err_ty, $extra);
+
+            fn try_from(raw: $extra) -> Result<Self, Self::Error> {
+                let v = $crate::__impl_enum_try_from!(@from_endian $endian, $extra, raw);
+                match <$type as TryFrom<$extra>>::try_from(v).ok().and_then(from_repr) {
+                    Some(v) => Ok(v),
+                    None => Err($crate::__impl_enum_try_from!(
+                        @type_err [$($err)*] $name $units $uranges $tranges, $type, $extra, raw, v
+                    )),
+                }
+            }
+        }
+    };
+    (@type_err_ty [@repr] $err_ty:ty, $extra:ty) => { $crate::TryFromReprError<$extra> };
+    (@type_err_ty $err:tt $err_ty:ty, $extra:ty) => { $err_ty };
+    // `TryFromReprError` lists only the values which fit in the type converted
+    // from, with the ranges cut to it.
+    (@type_err [@repr] $name:ident {
+        $($vname:ident [$(($alias:expr),)*] [$(($deprecated:expr),)*] $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {
+        $($tname:ident [$tstart:expr, $tend:expr],)*
+    }, $type:ty, $extra:ty, $raw:ident, $v:ident) => {{
+        const fn below(v: $type) -> bool {
+            <$type>::MIN != 0 && (v as i128) < 0 && (v as i128) < <$extra>::MIN as i128
+        }
+        const fn above(v: $type) -> bool {
+            !(<$type>::MIN != 0 && (v as i128) < 0) && v as u128 > <$extra>::MAX as u128
+        }
+
+        $crate::TryFromReprError {
+            value: $v,
+            enum_name: stringify!($name),
+            valid_values: {
+                const ALL: &[$type] = &[
+                    $(Discriminant::$vname as $type, $($alias,)* $($deprecated,)*)*
+                ];
+                const LEN: usize = {
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < ALL.len() {
+                        if !below(ALL[i]) && !above(ALL[i]) {
+                            len += 1;
+                        }
+                        i += 1;
+                    }
+                    len
+                };
+                const VALUES: [$extra; LEN] = {
+                    let mut values = [0; LEN];
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < ALL.len() {
+                        if !below(ALL[i]) && !above(ALL[i]) {
+                            values[len] = ALL[i] as $extra;
+                            len += 1;
+                        }
+                        i += 1;
+                    }
+                    values
+                };
+                &VALUES
+            },
+            valid_ranges: {
+                const ALL: &[($type, $type)] = &[$(($ustart, $uend),)* $(($tstart, $tend),)*];
+                const LEN: usize = {
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < ALL.len() {
+                        if !below(ALL[i].1) && !above(ALL[i].0) {
+                            len += 1;
+                        }
+                        i += 1;
+                    }
+                    len
+                };
+                const RANGES: [::core::ops::RangeInclusive<$extra>; LEN] = {
+                    const EMPTY: ::core::ops::RangeInclusive<$extra> = 0..=0;
+                    let mut ranges = [EMPTY; LEN];
+                    let mut len = 0;
+                    let mut i = 0;
+                    while i < ALL.len() {
+                        let (start, end) = ALL[i];
+                        if !below(end) && !above(start) {
+                            ranges[len] = ::core::ops::RangeInclusive::new(
+                                if below(start) { <$extra>::MIN } else { start as $extra },
+                                if above(end) { <$extra>::MAX } else { end as $extra },
+                            );
+                            len += 1;
+                        }
+                        i += 1;
+                    }
+                    ranges
+                };
+                &RANGES
+            },
+        }
+    }};
+    (@type_err $err:tt $name:ident $units:tt $uranges:tt $tranges:tt, $type:ty, $extra:ty,
+        $raw:ident, $v:ident) => {
+        $crate::__impl_enum_try_from!(@err $err $name $units $uranges $tranges, $extra, $raw, $v)
+    };
+
+    (@from_endian ne, $type:ty, $v:ident) => { $v };
+    (@from_endian be, $type:ty, $v:ident) => { <$type>::from_be($v) };
+    (@from_endian le, $type:ty, $v:ident) => { <$type>::from_le($v) };
+    (@from_endian runtime, $type:ty, $v:ident) => { $v };
+
+    (@to_endian ne, $type:ty, $v:ident) => { $v };
+    (@to_endian be, $type:ty, $v:ident) => { <$type>::to_be($v) };
+    (@to_endian le, $type:ty, $v:ident) => { <$type>::to_le($v) };
+    (@to_endian runtime, $type:ty, $v:ident) => { $v };
+
+    (@endian_fn runtime, $vis:vis $name:ident, $type:ty) => {
+        impl $name {
+            /// Converts the value in the given byte order to the enum.
+            $vis fn try_from_endian(
+                v: $type,
+                endian: $crate::Endian,
+            ) -> Result<Self, <Self as TryFrom<$type>>::Error> {
+                let v = match endian {
+                    $crate::Endian::Native => v,
+                    $crate::Endian::Big => <$type>::from_be(v),
+                    $crate::Endian::Little => <$type>::from_le(v),
+                };
+                <Self as TryFrom<$type>>::try_from(v)
+            }
+
+            /// Converts the enum to the value in the given byte order.
+            $vis fn to_endian(&self, endian: $crate::Endian) -> $type {
+                let v = <$type>::from(self);
+                match endian {
+                    $crate::Endian::Native => v,
+                    $crate::Endian::Big => <$type>::to_be(v),
+                    $crate::Endian::Little => <$type>::to_le(v),
+                }
+            }
+        }
+    };
+    (@endian_fn $endian:ident, $($rest:tt)*) => {};
+
+    // Conversions from the bytes of the value. `TryFrom<[u8; N]>` (or `From`
+    // for enums with a fallback variant) and `try_read_from` take the bytes in
+    // the byte order of `try_from`.
+    (@bytes [from] $endian:ident, $vis:vis $name:ident, $type:ty) => {
+        impl From<[u8; core::mem::size_of::<$type>()]> for $name {
+            fn from(bytes: [u8; core::mem::size_of::<$type>()]) -> Self {
+                <Self as From<$type>>::from(<$type>::from_ne_bytes(bytes))
+            }
+        }
+
+        $crate::__impl_enum_try_from! { @bytes_fn $endian, $vis $name, $type }
+    };
+    (@bytes [try] $endian:ident, $vis:vis $name:ident, $type:ty) => {
+        impl TryFrom<[u8; core::mem::size_of::<$type>()]> for $name {
+            type Error = <Self as TryFrom<$type>>::Error;
+
+            fn try_from(bytes: [u8; core::mem::size_of::<$type>()]) -> Result<Self, Self::Error> {
+                <Self as TryFrom<$type>>::try_from(<$type>::from_ne_bytes(bytes))
+            }
+        }
+
+        $crate::__impl_enum_try_from! { @bytes_fn $endian, $vis $name, $type }
+    };
+    (@bytes_fn $endian:ident, $vis:vis $name:ident, $type:ty) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// Converts the value from big endian bytes to the enum.
+            $vis fn try_from_be_bytes(
+                bytes: [u8; core::mem::size_of::<$type>()],
+            ) -> Result<Self, <Self as TryFrom<$type>>::Error> {
+                let v = <$type>::from_be_bytes(bytes);
+                <Self as TryFrom<$type>>::try_from($crate::__impl_enum_try_from!(@to_endian $endian, $type, v))
+            }
+
+            /// Converts the value from little endian bytes to the enum.
+            $vis fn try_from_le_bytes(
+                bytes: [u8; core::mem::size_of::<$type>()],
+            ) -> Result<Self, <Self as TryFrom<$type>>::Error> {
+                let v = <$type>::from_le_bytes(bytes);
+                <Self as TryFrom<$type>>::try_from($crate::__impl_enum_try_from!(@to_endian $endian, $type, v))
+            }
+
+            /// Converts the value from native endian bytes to the enum.
+            $vis fn try_from_ne_bytes(
+                bytes: [u8; core::mem::size_of::<$type>()],
+            ) -> Result<Self, <Self as TryFrom<$type>>::Error> {
+                let v = <$type>::from_ne_bytes(bytes);
+                <Self as TryFrom<$type>>::try_from($crate::__impl_enum_try_from!(@to_endian $endian, $type, v))
+            }
+
+            /// Reads the enum from the beginning of the bytes, returning it
+            /// together with the remaining bytes.
+            $vis fn try_read_from(
+                bytes: &[u8],
+            ) -> Result<(Self, &[u8]), $crate::TryReadError<<Self as TryFrom<$type>>::Error>> {
+                const SIZE: usize = core::mem::size_of::<$type>();
+                match bytes.split_first_chunk::<SIZE>() {
+                    Some((value, rest)) => match Self::try_from(*value) {
+                        Ok(v) => Ok((v, rest)),
+                        Err(e) => Err($crate::TryReadError::Invalid(e)),
+                    },
+                    None => Err($crate::TryReadError::Truncated {
+                        needed: SIZE,
+                        available: bytes.len(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+// `Serialize` and `Deserialize` implementations generated with the `serde`
+// option. Defined separately, so the option is rejected without the `serde`
+// feature.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from_serde {
+    ([no] $($rest:tt)*) => {};
+    ([value] $from_str:tt $name:ident $units:tt $uranges:tt $tranges:tt $other:tt, $type:ty,
+        $endian:ident) => {
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                $crate::__serde::Serialize::serialize(&to_repr(self), serializer)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+            {
+                let v = <$type as $crate::__serde::Deserialize>::deserialize(deserializer)?;
+                $crate::__impl_enum_try_from_serde!(@try_from $name, $type, $endian, v)
+            }
+        }
+    };
+    ([$mode:ident] [] $($rest:tt)*) => {
+        compile_error!(concat!(
+            "the `serde = \"", stringify!($mode), "\"` option requires the `from_str` option"
+        ));
+    };
+    ([name] [$case:ident] $name:ident $units:tt $uranges:tt {} [], $type:ty, $endian:ident) => {
+        const _: () = {
+            $crate::__impl_enum_try_from_serde! { @names $case $name $units $uranges {} [] }
+            $crate::__impl_enum_try_from_serde! { @visitor [name] $name, $type, $endian }
+
+            impl $crate::__serde::Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::__serde::Serializer,
+                {
+                    match name(self) {
+                        Some(name) => serializer.serialize_str(name),
+                        None => $crate::__serde::Serialize::serialize(&to_repr(self), serializer),
+                    }
+                }
+            }
+
+            impl<'de> $crate::__serde::Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+        };
+    };
+    ([name] $($rest:tt)*) => {
+        compile_error!(
+            "the `serde = \"name\"` option can't be used with tuple variants, use `serde = \"lenient\"`"
+        );
+    };
+    ([lenient] [$case:ident] $name:ident $units:tt $uranges:tt $tranges:tt $other:tt, $type:ty,
+        $endian:ident) => {
+        const _: () = {
+            $crate::__impl_enum_try_from_serde! { @names $case $name $units $uranges $tranges $other }
+            $crate::__impl_enum_try_from_serde! { @visitor [lenient] $name, $type, $endian }
+
+            impl $crate::__serde::Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: $crate::__serde::Serializer,
+                {
+                    match name(self) {
+                        Some(name) if serializer.is_human_readable() => serializer.serialize_str(name),
+                        _ => $crate::__serde::Serialize::serialize(&to_repr(self), serializer),
+                    }
+                }
+            }
+
+            impl<'de> $crate::__serde::Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: $crate::__serde::Deserializer<'de>,
+                {
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_any(Visitor)
+                    } else {
+                        let v = <$type as $crate::__serde::Deserialize>::deserialize(deserializer)?;
+                        $crate::__impl_enum_try_from_serde!(@try_from $name, $type, $endian, v)
+                    }
+                }
+            }
+        };
+    };
+
+    // Names of the unit variants, converted to the case of `from_str`. Tuple
+    // variants have no name.
+    (@names $case:ident $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt [$($rename:literal)?] $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {
+        $($tname:ident [$tstart:expr, $tend:expr],)*
+    } [$($other:ident)?]) => {
+        const CASE: $crate::__NameCase = $crate::__NameCase::$case;
+        const NAMES: &[&str] = &[
+            $($crate::__impl_enum_try_from!(@name_str CASE $vname [$($rename)?]),)*
+            $($crate::__impl_enum_try_from!(@name_str CASE $uname []),)*
+        ];
+
+        fn name(v: &$name) -> Option<&'static str> {
+            match v {
+                $($name::$vname => Some($crate::__impl_enum_try_from!(@name_str CASE $vname [$($rename)?])),)*
+                $($name::$uname => Some($crate::__impl_enum_try_from!(@name_str CASE $uname [])),)*
+                $($name::$tname(_) => None,)*
+                $($name::$other(_) => None,)?
+            }
+        }
+    };
+
+    // Visitor of the names and, in the lenient mode, the values of the enum.
+    (@visitor [$mode:ident] $name:ident, $type:ty, $endian:ident) => {
+        struct Visitor;
+
+        impl<'de> $crate::__serde::de::Visitor<'de> for Visitor {
+            type Value = $name;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::__impl_enum_try_from_serde!(@expecting $mode $name, f)
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<$name, E>
+            where
+                E: $crate::__serde::de::Error,
+            {
+                s.parse().map_err(|_| E::unknown_variant(s, NAMES))
+            }
+
+            $crate::__impl_enum_try_from_serde! {
+                @visit_int $mode $name, $type, $endian,
+                visit_u64 u64, visit_i64 i64, visit_u128 u128, visit_i128 i128
+            }
+        }
+    };
+    (@expecting name $name:ident, $f:ident) => {
+        $f.write_str(concat!("a name of `", stringify!($name), "`"))
+    };
+    (@expecting lenient $name:ident, $f:ident) => {
+        $f.write_str(concat!("a name or a value of `", stringify!($name), "`"))
+    };
+    (@visit_int name $($rest:tt)*) => {};
+    (@visit_int lenient $name:ident, $type:ty, $endian:ident, $($visit:ident $int:ty),*) => {
+        $(
+            fn $visit<E>(self, v: $int) -> Result<$name, E>
+            where
+                E: $crate::__serde::de::Error,
+            {
+                let v = <$type as TryFrom<$int>>::try_from(v).map_err(|_| {
+                    E::custom(format_args!("invalid value {} for `{}`", v, stringify!($name)))
+                })?;
+                $crate::__impl_enum_try_from_serde!(@try_from $name, $type, $endian, v)
+            }
+        )*
+    };
+
+    // Convert the value in native byte order with the `try_from` of the enum.
+    (@try_from $name:ident, $type:ty, $endian:ident, $v:ident) => {
+        <$name as TryFrom<$type>>::try_from($crate::__impl_enum_try_from!(@to_endian $endian, $type, $v))
+            .map_err(|_| {
+                $crate::__serde::de::Error::custom(format_args!(
+                    "invalid value {} for `{}`",
+                    $v,
+                    stringify!($name)
+                ))
+            })
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from_serde {
+    ([no] $($rest:tt)*) => {};
+    ($($rest:tt)*) => {
+        compile_error!("the `serde` option requires the `serde` feature of `enum-try-from`");
+    };
+}
+
+// `CheckedBitPattern`, `NoUninit` and `Contiguous` implementations generated
+// with the `bytemuck` option.
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from_bytemuck {
+    ([no] $($rest:tt)*) => {};
+    ([$mode:ident] [] $($rest:tt)*) => {
+        compile_error!("the `bytemuck` option requires a primitive `#[repr(...)]`");
+    };
+    ([checked] [$prim:ident] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {} []) => {
+        // The `repr` can also change the alignment, i.e. `#[repr(u8, align(4))]`,
+        // which would make the casts read past the bytes and expose padding.
+        const _: () = assert!(
+            ::core::mem::size_of::<$name>() == ::core::mem::size_of::<$prim>()
+                && ::core::mem::align_of::<$name>() == ::core::mem::align_of::<$prim>(),
+            concat!("the layout of `", stringify!($name), "` differs from `", stringify!($prim), "`"),
+        );
+
+        // SAFETY: The enum is fieldless and has the layout of `$prim`, as
+        // asserted above. Only the discriminants of its variants are valid bit
+        // patterns.
+        unsafe impl $crate::__bytemuck::CheckedBitPattern for $name {
+            type Bits = $prim;
+
+            fn is_valid_bit_pattern(bits: &$prim) -> bool {
+                // Constants named after the variants, which can be used as
+                // patterns.
+                struct Values;
+                #[allow(non_upper_case_globals)]
+                impl Values {
+                    $(const $vname: $prim = Discriminant::$vname as $prim;)*
+                    $(const $uname: $prim = Discriminant::$uname as $prim;)*
+                }
+                match *bits {
+                    $(Values::$vname => true,)*
+                    $(Values::$uname => true,)*
+                    _ => false,
+                }
+            }
+        }
+
+        // SAFETY: The enum is fieldless and has the layout of `$prim`, as
+        // asserted above, so it has no padding.
+        unsafe impl $crate::__bytemuck::NoUninit for $name {}
+    };
+    ([contiguous] [$prim:ident] $name:ident {
+        $($vname:ident $aliases:tt $deprecated:tt $rename:tt $names:tt,)*
+    } {
+        $($uname:ident [$ustart:expr, $uend:expr],)*
+    } {} []) => {
+        $crate::__impl_enum_try_from_bytemuck! {
+            [checked] [$prim] $name {
+                $($vname $aliases $deprecated $rename $names,)*
+            } {
+                $($uname [$ustart, $uend],)*
+            } {} []
+        }
+
+        const _: () = {
+            const DISCRIMINANTS: &[$prim] = &[
+                $(Discriminant::$vname as $prim,)*
+                $(Discriminant::$uname as $prim,)*
+            ];
+            // The lowest and the highest discriminant.
+            const BOUNDS: ($prim, $prim) = {
+                assert!(
+                    !DISCRIMINANTS.is_empty(),
+                    concat!("`", stringify!($name), "` has no unit variants"),
+                );
+                let (mut min, mut max) = (DISCRIMINANTS[0], DISCRIMINANTS[0]);
+                let mut i = 1;
+                while i < DISCRIMINANTS.len() {
+                    if DISCRIMINANTS[i] < min {
+                        min = DISCRIMINANTS[i];
+                    }
+                    if DISCRIMINANTS[i] > max {
+                        max = DISCRIMINANTS[i];
+                    }
+                    i += 1;
+                }
+                // The discriminants are distinct, so they are contiguous if
+                // there are as many of them as values between the bounds.
+                assert!(
+                    max as i128 - min as i128 + 1 == DISCRIMINANTS.len() as i128,
+                    concat!("the discriminants of `", stringify!($name), "` aren't contiguous"),
+                );
+                (min, max)
+            };
+
+            // SAFETY: The enum is fieldless, has the layout of `$prim`, as
+            // asserted by the `checked` implementations above, and its
+            // discriminants are all the values between the bounds.
+            unsafe impl $crate::__bytemuck::Contiguous for $name {
+                type Int = $prim;
+
+                const MIN_VALUE: $prim = BOUNDS.0;
+                const MAX_VALUE: $prim = BOUNDS.1;
+            }
+        };
+    };
+    ([$mode:ident] $($rest:tt)*) => {
+        compile_error!("the `bytemuck` option can't be used with tuple variants");
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from_bytemuck {
+    ([no] $($rest:tt)*) => {};
+    ($($rest:tt)*) => {
+        compile_error!("the `bytemuck` option requires the `bytemuck` feature of `enum-try-from`");
+    };
+}
+
+// Definition of the enum, deriving `TryFromBytes`, `IntoBytes`, `KnownLayout`
+// and `Immutable` with the `zerocopy` option. The traits can be implemented
+// only by the derives of `zerocopy`, which check the variants themselves.
+#[cfg(feature = "zerocopy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from_zerocopy {
+    ([no] $($item:tt)*) => {
+        $($item)*
+    };
+    ([zerocopy] $($item:tt)*) => {
+        #[derive(
+            $crate::__zerocopy::TryFromBytes,
+            $crate::__zerocopy::IntoBytes,
+            $crate::__zerocopy::KnownLayout,
+            $crate::__zerocopy::Immutable,
+        )]
+        #[zerocopy(crate = "enum_try_from::__zerocopy")]
+        $($item)*
+    };
+    ([$path:tt] $($item:tt)*) => {
+        #[derive(
+            $crate::__zerocopy::TryFromBytes,
+            $crate::__zerocopy::IntoBytes,
+            $crate::__zerocopy::KnownLayout,
+            $crate::__zerocopy::Immutable,
+        )]
+        #[zerocopy(crate = $path)]
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "zerocopy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_enum_try_from_zerocopy {
+    ([no] $($item:tt)*) => {
+        $($item)*
+    };
+    ([$zerocopy:tt] $($item:tt)*) => {
+        compile_error!("the `zerocopy` option requires the `zerocopy` feature of `enum-try-from`");
+
+        $($item)*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_impl_enum_try_from() {
+        impl_enum_try_from!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            (),
+            ()
+        );
+
+        assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+        assert_eq!(Test::try_from(0x5678), Ok(Test::Test2));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_reverse() {
+        impl_enum_try_from!(
+            #[repr(u16)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            (),
+            ()
+        );
+
+        assert_eq!(u16::from(Test::Test), 0x1234);
+        assert_eq!(u16::from(&Test::Test2), 0x5678);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_be() {
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            (),
+            ()
+        );
+
+        assert_eq!(Test::try_from(0x3412), Ok(Test::Test));
+        assert_eq!(Test::try_from(0x7856), Ok(Test::Test2));
+
+        assert_eq!(u16::from(Test::Test).to_be_bytes(), [0x34, 0x12]);
+        assert_eq!(Test::try_from(u16::from(Test::Test2)), Ok(Test::Test2));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_le() {
+        impl_enum_try_from_le!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            (),
+            ()
+        );
+
+        assert_eq!(
+            Test::try_from(u16::from_ne_bytes([0x34, 0x12])),
+            Ok(Test::Test)
+        );
+        assert_eq!(
+            Test::try_from(u16::from_ne_bytes([0x78, 0x56])),
+            Ok(Test::Test2)
+        );
+
+        assert_eq!(u16::from(Test::Test).to_ne_bytes(), [0x34, 0x12]);
+        assert_eq!(Test::try_from(u16::from(Test::Test2)), Ok(Test::Test2));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_endian() {
+        impl_enum_try_from!(
+            #[try_from(endian = "be")]
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            (),
+            ()
+        );
+
+        assert_eq!(Test::try_from(0x1234_u16.to_be()), Ok(Test::Test));
+        assert_eq!(Test::try_from(0x5678_u16.to_be()), Ok(Test::Test2));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_endian_runtime() {
+        use crate::Endian;
+
+        impl_enum_try_from!(
+            #[try_from(endian = "runtime")]
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            (),
+            ()
+        );
+
+        assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+        assert_eq!(
+            Test::try_from_endian(0x1234_u16.to_be(), Endian::Big),
+            Ok(Test::Test)
+        );
+        assert_eq!(
+            Test::try_from_endian(0x5678_u16.to_le(), Endian::Little),
+            Ok(Test::Test2)
+        );
+        assert_eq!(
+            Test::try_from_endian(0x5678, Endian::Native),
+            Ok(Test::Test2)
+        );
+        assert_eq!(Test::try_from_endian(0x9abc, Endian::Big), Err(()));
+
+        assert_eq!(u16::from(Test::Test), 0x1234);
+        assert_eq!(Test::Test.to_endian(Endian::Big), 0x1234_u16.to_be());
+        assert_eq!(Test::Test2.to_endian(Endian::Little), 0x5678_u16.to_le());
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_infer_type() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum TestError {
+            InvalidValue(u8),
+        }
+
+        impl_enum_try_from!(
+            #[derive(PartialEq, Eq, Debug)]
+            #[repr(i16)]
+            enum Test {
+                Test = -0x1234,
+                Test2 = 0x5678,
+            },
+            TestError,
+            TestError::InvalidValue(0)
+        );
+
+        assert_eq!(Test::try_from(-0x1234_i16), Ok(Test::Test));
+        assert_eq!(Test::try_from(0x5678_i16), Ok(Test::Test2));
+        assert_eq!(Test::try_from(0_i16), Err(TestError::InvalidValue(0)));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_narrower_type() {
+        impl_enum_try_from!(
+            #[repr(i32)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = -128,
+                Test2 = 127,
+            },
+            i8,
+            (),
+            ()
+        );
+
+        assert_eq!(Test::try_from(-128_i8), Ok(Test::Test));
+        assert_eq!(Test::try_from(127_i8), Ok(Test::Test2));
+        assert_eq!(Test::try_from(0_i8), Err(()));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_default_error() {
+        extern crate std;
+        use crate::TryFromReprError;
+        use std::string::ToString;
+
+        impl_enum_try_from!(
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+        );
+
+        assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+        assert_eq!(
+            Test::try_from(0x9abc),
+            Err(TryFromReprError {
+                value: 0x9abc,
+                enum_name: "Test",
+                valid_values: &[0x1234, 0x5678],
+                valid_ranges: &[],
+            })
+        );
+        assert_eq!(
+            Test::try_from(0x9abc).unwrap_err().to_string(),
+            "invalid value 39612 for `Test`, expected one of: 4660, 22136"
+        );
+
+        impl_enum_try_from!(
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Ranges {
+                #[try_from(alias = 0x85, deprecated_alias = 0x05)]
+                Foo = 0x01,
+                Reserved = 0x10..=0x1f,
+                Vendor(u8) = 0x80..=0x84,
+            }
+        );
+
+        assert_eq!(Ranges::try_from(0x15), Ok(Ranges::Reserved));
+        assert_eq!(
+            Ranges::try_from(0x20),
+            Err(TryFromReprError {
+                value: 0x20,
+                enum_name: "Ranges",
+                valid_values: &[0x01, 0x85, 0x05],
+                valid_ranges: &[0x10..=0x1f, 0x80..=0x84],
+            })
+        );
+        assert_eq!(
+            Ranges::try_from(0x20).unwrap_err().to_string(),
+            "invalid value 32 for `Ranges`, expected one of: 1, 133, 5, 16..=31, 128..=132"
+        );
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_default_error_be() {
+        use crate::TryFromReprError;
+
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            }
+        );
+
+        assert_eq!(
+            Test::try_from(0x9abc_u16.to_be()),
+            Err(TryFromReprError {
+                value: 0x9abc,
+                enum_name: "Test",
+                valid_values: &[0x1234, 0x5678],
+                valid_ranges: &[],
+            })
+        );
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_const() {
+        use crate::EnumRepr;
+
+        impl_enum_try_from_be!(
+            #[try_from(strategy = "binary_search")]
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                #[try_from(alias = 0x9abc)]
+                Foo = 0x1234,
+                Bar = 0x5678,
+                Reserved = 0xff00..=0xffff,
+            }
+        );
+
+        const FOO: Option<Test> = Test::from_ne_repr(0x9abc);
+        const BAR: Option<Test> = Test::from_be_repr(0x5678_u16.to_be());
+        const RESERVED: u16 = Test::Reserved.ne_repr();
+        const RESERVED_BE: u16 = Test::Reserved.be_repr();
+
+        assert_eq!(FOO, Some(Test::Foo));
+        assert_eq!(BAR, Some(Test::Bar));
+        assert_eq!(Test::from_repr(0xff10_u16.to_be()), Some(Test::Reserved));
+        assert_eq!(Test::from_repr(0xdef0_u16.to_be()), None);
+        assert_eq!(Test::Reserved.repr(), 0xff00_u16.to_be());
+        assert_eq!(
+            Test::from_repr(0x1234_u16.to_be()),
+            <Test as EnumRepr>::from_repr(0x1234_u16.to_be())
+        );
+        assert_eq!(RESERVED, 0xff00);
+        assert_eq!(RESERVED_BE, 0xff00_u16.to_be());
+
+        impl_enum_try_from!(
+            #[try_from(strategy = "table")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Table {
+                Foo = 1,
+                Bar = 2,
+            }
+        );
+
+        static TABLE: [Option<Table>; 3] = [
+            Table::from_repr(0),
+            Table::from_repr(1),
+            Table::from_repr(2),
+        ];
+
+        assert_eq!(TABLE, [None, Some(Table::Foo), Some(Table::Bar)]);
+    }
+
+    #[test]
+    fn test_const_try_from() {
+        impl_enum_try_from!(
+            #[repr(i8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = -1,
+                Bar = 0x10..=0x1f,
+                #[try_from(other)]
+                Unknown(i8),
+            }
+        );
+
+        const BAR: i8 = 0x18;
+
+        assert_eq!(const_try_from!(Test, -1), Test::Foo);
+        assert_eq!(const_try_from!(Test, BAR), Test::Bar);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_types() {
+        use crate::TryFromReprError;
+
+        impl_enum_try_from!(
+            #[repr(i16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Minus = -1,
+                Foo = 0x12,
+                Bar = 0x1234,
+            },
+            [i16, u8, i32, u64],
+        );
+
+        assert_eq!(Test::try_from(-1_i16), Ok(Test::Minus));
+        assert_eq!(Test::try_from(0x12_u8), Ok(Test::Foo));
+        assert_eq!(Test::try_from(-1_i32), Ok(Test::Minus));
+        assert_eq!(Test::try_from(0x1234_u64), Ok(Test::Bar));
+        assert_eq!(
+            Test::try_from(0x1_0012_i32),
+            Err(TryFromReprError {
+                value: 0x1_0012,
+                enum_name: "Test",
+                valid_values: &[-1, 0x12, 0x1234],
+                valid_ranges: &[],
+            })
+        );
+        assert_eq!(
+            Test::try_from(u64::MAX),
+            Err(TryFromReprError {
+                value: u64::MAX,
+                enum_name: "Test",
+                valid_values: &[0x12, 0x1234],
+                valid_ranges: &[],
+            })
+        );
+        assert_eq!(
+            Test::try_from(0xff_u8),
+            Err(TryFromReprError {
+                value: 0xff,
+                enum_name: "Test",
+                valid_values: &[0x12],
+                valid_ranges: &[],
+            })
+        );
+
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Default {
+                #[try_from(default)]
+                Unknown,
+                Test = 0x1234,
+            },
+            [u16, u32],
+        );
+
+        assert_eq!(Default::from(0x1234_u32.to_be()), Default::Test);
+        assert_eq!(Default::from(0x1_1234_u32.to_be()), Default::Unknown);
+
+        impl_enum_try_from!(
+            #[repr(i16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Ranges {
+                #[try_from(alias = 0x7f, alias = 0x1234)]
+                Foo = -0x20,
+                Low = -0x10..=0x10,
+                Negative = -0x1f..=-0x11,
+                High = 0x80..=0x1ff,
+                Reserved(i16) = 0x2000..=0x2fff,
+            },
+            [i16, u8],
+        );
+
+        assert_eq!(
+            Ranges::try_from(0x20_i16),
+            Err(TryFromReprError {
+                value: 0x20,
+                enum_name: "Ranges",
+                valid_values: &[-0x20, 0x7f, 0x1234],
+                valid_ranges: &[-0x10..=0x10, -0x1f..=-0x11, 0x80..=0x1ff, 0x2000..=0x2fff],
+            })
+        );
+        // The values and ranges which don't fit in `u8` are left out, and the
+        // ranges which fit partially are cut.
+        assert_eq!(
+            Ranges::try_from(0x20_u8),
+            Err(TryFromReprError {
+                value: 0x20,
+                enum_name: "Ranges",
+                valid_values: &[0x7f],
+                valid_ranges: &[0x00..=0x10, 0x80..=0xff],
+            })
+        );
+    }
+
+    #[test]
+    fn test_maybe_known() {
+        use crate::{EnumRepr, MaybeKnown};
+
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            }
+        );
+
+        let known = MaybeKnown::<Test>::from(0x5678_u16.to_be());
+        assert_eq!(known.known(), Some(Test::Test2));
+        let unknown = MaybeKnown::<Test>::from(0x9abc_u16.to_be());
+        assert!(!unknown.is_known());
+        assert_eq!(unknown, MaybeKnown::Unknown(0x9abc_u16.to_be()));
+        assert_eq!(u16::from(unknown), 0x9abc_u16.to_be());
+        assert_eq!(
+            MaybeKnown::from(Test::Test),
+            MaybeKnown::Unknown(0x1234_u16.to_be())
+        );
+        assert!(MaybeKnown::Known(Test::Test) < MaybeKnown::Unknown(0x5678_u16.to_be()));
+
+        impl_enum_try_from!(
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Other {
+                Foo = 1,
+                #[try_from(other)]
+                Unknown(u8),
+            }
+        );
+
+        assert_eq!(<Other as EnumRepr>::from_repr(1), Some(Other::Foo));
+        assert_eq!(<Other as EnumRepr>::from_repr(2), None);
+        assert_eq!(Other::Unknown(2).to_repr(), 2);
+
+        impl_enum_try_from!(
+            #[repr(u8)]
+            #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+            enum Lossy {
+                #[try_from(alias = 0x85)]
+                Foo = 0x05,
+                Range = 0x10..=0x1f,
+            }
+        );
+
+        // Aliases and values inside ranges are converted back to the value of
+        // their variant.
+        let alias = MaybeKnown::<Lossy>::from(0x85);
+        assert_eq!(alias.known(), Some(Lossy::Foo));
+        assert_eq!(u8::from(alias), 0x05);
+        let range = MaybeKnown::<Lossy>::from(0x15);
+        assert_eq!(range.known(), Some(Lossy::Range));
+        assert_eq!(u8::from(range), 0x10);
+        assert_eq!(range, MaybeKnown::from(0x10));
+        assert_eq!(u8::from(MaybeKnown::<Lossy>::from(0x20)), 0x20);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_error_closure() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum TestError {
+            InvalidValue(u16),
+            InvalidValueRaw(u16, u16),
+        }
+
+        impl_enum_try_from!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            TestError,
+            |v| TestError::InvalidValue(v)
+        );
+
+        assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+        assert_eq!(Test::try_from(0x9abc), Err(TestError::InvalidValue(0x9abc)));
+
+        impl_enum_try_from_le!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum TestLe {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            TestError,
+            move |raw, v| TestError::InvalidValueRaw(raw, v),
+        );
+
+        assert_eq!(
+            TestLe::try_from(0x9abc_u16.to_le()),
+            Err(TestError::InvalidValueRaw(0x9abc_u16.to_le(), 0x9abc))
+        );
+
+        impl_enum_try_from!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Typed {
+                Test = 0x1234,
+            },
+            u16,
+            TestError,
+            |v: u16| TestError::InvalidValue(v)
+        );
+
+        assert_eq!(
+            Typed::try_from(0x9abc),
+            Err(TestError::InvalidValue(0x9abc))
+        );
+
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum TypedBe {
+                Test = 0x1234,
+            },
+            u16,
+            TestError,
+            |raw: u16, v| TestError::InvalidValueRaw(raw, v),
+        );
+
+        assert_eq!(
+            TypedBe::try_from(0x9abc_u16.to_be()),
+            Err(TestError::InvalidValueRaw(0x9abc_u16.to_be(), 0x9abc))
+        );
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_error_fn() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum TestError {
+            InvalidValue(u16),
+        }
+
+        impl_enum_try_from_be!(
+            #[try_from(error_fn)]
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            TestError,
+            TestError::InvalidValue
+        );
+
+        assert_eq!(Test::try_from(0x1234_u16.to_be()), Ok(Test::Test));
+        assert_eq!(
+            Test::try_from(0x9abc_u16.to_be()),
+            Err(TestError::InvalidValue(0x9abc_u16.to_be()))
+        );
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_generic_error() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct GenericError<T, U>(T, U);
+
+        impl_enum_try_from!(
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Test = 0x1234,
+                Test2 = 0x5678,
+            },
+            u16,
+            GenericError<u16, u8>,
+            GenericError(0, 0)
+        );
+
+        assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
+        assert_eq!(Test::try_from(0x9abc), Err(GenericError(0, 0)));
+
+        impl_enum_try_from!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Nested {
+                Foo = 0x1234,
+            },
+            GenericError<u16, Option<GenericError<u8, u8>>>,
+            |v| GenericError(v, None)
+        );
+
+        assert_eq!(Nested::try_from(0x9abc), Err(GenericError(0x9abc, None)));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_documented_variants() {
+        // Defines enums with the variants, with a doc comment on each of them,
+        // and followed by a variant with `#[try_from(...)]` options.
+        macro_rules! documented {
+            ($($vname:ident)*) => {
+                impl_enum_try_from!(
+                    #[repr(u8)]
+                    #[derive(PartialEq, Eq, Debug)]
+                    enum Test {
+                        $(
+                            /// An opcode.
+                            $vname,
+                        )*
+                    }
+                );
+
+                impl_enum_try_from!(
+                    #[repr(u8)]
+                    #[derive(PartialEq, Eq, Debug)]
+                    enum Alias {
+                        $(
+                            /// An opcode.
+                            $vname,
+                        )*
+                        /// The last opcode.
+                        #[try_from(alias = 0xff)]
+                        Last,
+                    }
+                );
+
+                impl_enum_try_from!(
+                    #[repr(u8)]
+                    #[derive(PartialEq, Eq, Debug)]
+                    enum Default {
+                        $($vname,)*
+                        #[try_from(default)]
+                        Unknown,
+                    }
+                );
+            };
+        }
+
+        documented!(
+                V0 V1 V2 V3 V4 V5 V6 V7 V8 V9 V10 V11 V12 V13 V14 V15 V16 V17 V18 V19 V20 V21 V22
+                V23 V24 V25 V26 V27 V28 V29 V30 V31 V32 V33 V34 V35 V36 V37 V38 V39 V40 V41 V42 V43
+                V44 V45 V46 V47 V48 V49 V50 V51 V52 V53 V54 V55 V56 V57 V58 V59 V60 V61 V62 V63 V64
+                V65 V66 V67 V68 V69 V70 V71 V72 V73 V74 V75 V76 V77 V78 V79 V80 V81 V82 V83 V84 V85
+                V86 V87 V88 V89 V90 V91 V92 V93 V94 V95 V96 V97 V98 V99 V100 V101 V102 V103 V104
+                V105 V106 V107 V108 V109 V110 V111 V112 V113 V114 V115 V116 V117 V118 V119 V120
+                V121 V122 V123 V124 V125 V126 V127 V128 V129 V130 V131 V132 V133 V134 V135 V136
+                V137 V138 V139 V140 V141 V142 V143 V144 V145 V146 V147 V148 V149 V150 V151 V152
+                V153 V154 V155 V156 V157 V158 V159 V160 V161 V162 V163 V164 V165 V166 V167 V168
+                V169 V170 V171 V172 V173 V174 V175 V176 V177 V178 V179 V180 V181 V182 V183 V184
+                V185 V186 V187 V188 V189 V190 V191 V192 V193 V194 V195 V196 V197 V198 V199
+        );
+
+        assert_eq!(Test::try_from(5), Ok(Test::V5));
+        assert_eq!(Test::try_from(199), Ok(Test::V199));
+        assert!(Test::try_from(200).is_err());
+
+        assert_eq!(Alias::try_from(199), Ok(Alias::V199));
+        assert_eq!(Alias::try_from(0xff), Ok(Alias::Last));
+        assert_eq!(u8::from(Alias::Last), 200);
+        assert!(Alias::try_from(201).is_err());
+
+        assert_eq!(Default::from(5), Default::V5);
+        assert_eq!(Default::from(201), Default::Unknown);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_128() {
+        impl_enum_try_from!(
+            #[repr(u128)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Big {
+                A = 1,
+                B = u128::MAX,
+            }
+        );
+
+        assert_eq!(Big::try_from(1), Ok(Big::A));
+        assert_eq!(Big::try_from(u128::MAX), Ok(Big::B));
+        assert!(Big::try_from(2).is_err());
+
+        impl_enum_try_from!(
+            #[repr(i128)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Signed {
+                Min = i128::MIN,
+                Max = i128::MAX,
+            }
+        );
+
+        assert_eq!(Signed::try_from(i128::MIN), Ok(Signed::Min));
+        assert_eq!(Signed::try_from(i128::MAX), Ok(Signed::Max));
+        assert!(Signed::try_from(0).is_err());
+
+        // Dense around the middle of the `u128` range, which uses the table.
+        impl_enum_try_from!(
+            #[try_from(strategy = "table")]
+            #[repr(u128)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Middle {
+                A = 0x7fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+                B = 0x8000_0000_0000_0000_0000_0000_0000_0000,
+            }
+        );
+
+        assert_eq!(Middle::try_from(1 << 127), Ok(Middle::B));
+        assert_eq!(Middle::try_from((1 << 127) - 1), Ok(Middle::A));
+        assert!(Middle::try_from((1 << 127) + 1).is_err());
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_other() {
+        impl_enum_try_from!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 0x1234,
+                #[try_from(other)]
+                Unknown(u16),
+                Bar = 0x5678,
+            }
+        );
+
+        assert_eq!(Test::from(0x1234), Test::Foo);
+        assert_eq!(Test::from(0x5678), Test::Bar);
+        assert_eq!(Test::from(0x9abc), Test::Unknown(0x9abc));
+
+        assert_eq!(u16::from(Test::Bar), 0x5678);
+        assert_eq!(u16::from(&Test::Unknown(0x9abc)), 0x9abc);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_other_be() {
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 0x1234,
+                #[try_from(other)]
+                Unknown(u16),
+            }
+        );
+
+        assert_eq!(Test::from(0x1234_u16.to_be()), Test::Foo);
+        assert_eq!(Test::from(0x9abc_u16.to_be()), Test::Unknown(0x9abc));
+        assert_eq!(u16::from(Test::Unknown(0x9abc)), 0x9abc_u16.to_be());
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_default() {
+        impl_enum_try_from_le!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 0x1234,
+                #[try_from(default)]
+                Unspecified = 0,
+                Bar = 0x5678,
+            }
+        );
+
+        assert_eq!(Test::from(0x1234_u16.to_le()), Test::Foo);
+        assert_eq!(Test::from(0x5678_u16.to_le()), Test::Bar);
+        assert_eq!(Test::from(0x9abc_u16.to_le()), Test::Unspecified);
+        assert_eq!(u16::from(Test::Unspecified), 0);
+        assert_eq!(u16::from(Test::Bar), 0x5678_u16.to_le());
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_impl_default() {
+        impl_enum_try_from!(
+            #[try_from(impl_default)]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 1,
+                #[try_from(default)]
+                Unspecified = 0,
+            }
+        );
+
+        assert_eq!(Test::from(1), Test::Foo);
+        assert_eq!(Test::from(2), Test::Unspecified);
+        assert_eq!(Test::default(), Test::Unspecified);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_range() {
+        impl_enum_try_from!(
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 0x01,
+                Reserved = 0x10..=0x1f,
+                Bar = 0x02,
+                Vendor(u8) = 0x80..=0xff,
+            }
+        );
+
+        assert_eq!(Test::try_from(0x01), Ok(Test::Foo));
+        assert_eq!(Test::try_from(0x02), Ok(Test::Bar));
+        assert_eq!(Test::try_from(0x10), Ok(Test::Reserved));
+        assert_eq!(Test::try_from(0x15), Ok(Test::Reserved));
+        assert_eq!(Test::try_from(0x1f), Ok(Test::Reserved));
+        assert_eq!(Test::try_from(0x80), Ok(Test::Vendor(0x80)));
+        assert_eq!(Test::try_from(0xff), Ok(Test::Vendor(0xff)));
+        assert!(Test::try_from(0x20).is_err());
+
+        assert_eq!(u8::from(Test::Reserved), 0x10);
+        assert_eq!(u8::from(Test::Vendor(0x9a)), 0x9a);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_range_be() {
+        const VENDOR_START: u16 = 0x8000;
+
+        impl_enum_try_from_be!(
+            #[repr(u16)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 0x1234,
+                Vendor(u16) = VENDOR_START..=0xffff,
+                #[try_from(other)]
+                Unknown(u16),
+            }
+        );
+
+        assert_eq!(Test::from(0x1234_u16.to_be()), Test::Foo);
+        assert_eq!(Test::from(0x9abc_u16.to_be()), Test::Vendor(0x9abc));
+        assert_eq!(Test::from(0x5678_u16.to_be()), Test::Unknown(0x5678));
+        assert_eq!(u16::from(Test::Vendor(0x9abc)), 0x9abc_u16.to_be());
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_alias() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DEPRECATED: AtomicUsize = AtomicUsize::new(0);
+
+        fn on_deprecated_alias(v: u8, variant: &Test) {
+            assert_eq!((v, variant), (0x03, &Test::Foo));
+            DEPRECATED.fetch_add(1, Ordering::Relaxed);
         }
 
-        impl TryFrom<$type> for $name {
-            type Error = $err_ty;
+        impl_enum_try_from!(
+            #[try_from(deprecated_alias_hook = on_deprecated_alias)]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                #[try_from(alias = 0x05, alias = 0x85)]
+                #[try_from(deprecated_alias = 0x03)]
+                Foo = 0x01,
+                Bar = 0x02,
+            }
+        );
+
+        assert_eq!(Test::try_from(0x01), Ok(Test::Foo));
+        assert_eq!(Test::try_from(0x05), Ok(Test::Foo));
+        assert_eq!(Test::try_from(0x85), Ok(Test::Foo));
+        assert_eq!(Test::try_from(0x02), Ok(Test::Bar));
+        assert!(Test::try_from(0x04).is_err());
+        assert_eq!(DEPRECATED.load(Ordering::Relaxed), 0);
 
-            fn try_from(v: $type) -> Result<Self, Self::Error> {
-                let v = <$type>::from_be(v);
-                match v {
-                    $(x if x == $name::$vname as $type => Ok($name::$vname),)*
-                    _ => Err($err),
-                }
+        assert_eq!(Test::try_from(0x03), Ok(Test::Foo));
+        assert_eq!(DEPRECATED.load(Ordering::Relaxed), 1);
+
+        assert_eq!(u8::from(Test::try_from(0x85).unwrap()), 0x01);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_consts() {
+        impl_enum_try_from!(
+            #[try_from(consts)]
+            #[repr(i8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Test {
+                Foo = 0x05,
+                Bar = -0x02,
+                #[try_from(other)]
+                Unknown(i8),
+                Reserved = 0x10..=0x1f,
+                Baz = 0x01,
+            }
+        );
+
+        assert_eq!(Test::ALL, [Test::Bar, Test::Baz, Test::Foo, Test::Reserved]);
+        assert_eq!(Test::VALUES, [-0x02, 0x01, 0x05, 0x10]);
+        assert_eq!(Test::COUNT, 4);
+        assert_eq!(Test::MIN, -0x02);
+        assert_eq!(Test::MAX, 0x10);
+
+        // Without the option, the enum can define constants of the same names.
+        impl_enum_try_from!(
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Own {
+                Foo = 1,
+                Bar = 2,
             }
+        );
+
+        impl Own {
+            const ALL: [Self; 2] = [Own::Foo, Own::Bar];
+            const COUNT: usize = 2;
         }
+
+        assert_eq!(Own::ALL, [Own::Foo, Own::Bar]);
+        assert_eq!(Own::COUNT, 2);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+    #[test]
+    fn test_impl_enum_try_from_from_str() {
+        use crate::ParseNameError;
+
+        impl_enum_try_from!(
+            #[try_from(from_str)]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Exact {
+                #[try_from(rename = "foo", name_alias = "FOO")]
+                Foo = 0x01,
+                BarBaz = 0x02,
+                Reserved = 0x10..=0x1f,
+            }
+        );
+
+        assert_eq!("foo".parse(), Ok(Exact::Foo));
+        assert_eq!("FOO".parse(), Ok(Exact::Foo));
+        assert_eq!(Exact::try_from("BarBaz"), Ok(Exact::BarBaz));
+        assert_eq!(Exact::try_from("Reserved"), Ok(Exact::Reserved));
+        assert_eq!(
+            "Foo".parse::<Exact>(),
+            Err(ParseNameError { enum_name: "Exact" })
+        );
+        assert!(Exact::try_from("barbaz").is_err());
+
+        impl_enum_try_from!(
+            #[try_from(from_str = "case_insensitive")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum CaseInsensitive {
+                #[try_from(name_alias = "Legacy")]
+                Foo = 0x01,
+                BarBaz = 0x02,
+            }
+        );
+
+        assert_eq!("FOO".parse(), Ok(CaseInsensitive::Foo));
+        assert_eq!("legacy".parse(), Ok(CaseInsensitive::Foo));
+        assert_eq!("barBAZ".parse(), Ok(CaseInsensitive::BarBaz));
+        assert!("bar_baz".parse::<CaseInsensitive>().is_err());
+
+        impl_enum_try_from!(
+            #[try_from(from_str = "snake_case")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum SnakeCase {
+                #[try_from(rename = "Foo")]
+                Foo = 0x01,
+                BarBaz = 0x02,
+                HTTPRequest = 0x03,
+                Ipv4Address = 0x04,
+            }
+        );
+
+        assert_eq!("Foo".parse(), Ok(SnakeCase::Foo));
+        assert_eq!("bar_baz".parse(), Ok(SnakeCase::BarBaz));
+        assert_eq!("http_request".parse(), Ok(SnakeCase::HTTPRequest));
+        assert_eq!("ipv4_address".parse(), Ok(SnakeCase::Ipv4Address));
+        assert!("foo".parse::<SnakeCase>().is_err());
+        assert!("BarBaz".parse::<SnakeCase>().is_err());
+        assert!("bar_baz_".parse::<SnakeCase>().is_err());
+
+        impl_enum_try_from!(
+            #[try_from(from_str = "kebab_case")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum KebabCase {
+                BarBaz = 0x02,
+                HTTPRequest = 0x03,
+            }
+        );
+
+        assert_eq!("bar-baz".parse(), Ok(KebabCase::BarBaz));
+        assert_eq!("http-request".parse(), Ok(KebabCase::HTTPRequest));
+        assert!("bar_baz".parse::<KebabCase>().is_err());
+    }
 
     #[test]
-    fn test_impl_enum_try_from() {
+    fn test_impl_enum_try_from_fmt() {
+        extern crate std;
+        use std::{format, string::ToString};
+
+        impl_enum_try_from!(
+            #[try_from(from_str = "snake_case", display, debug, hex)]
+            #[repr(u16)]
+            #[derive(PartialEq, Eq)]
+            enum Test {
+                #[try_from(rename = "FOO")]
+                Foo = 0x1234,
+                BarBaz = 0x5678,
+                Reserved(u16) = 0xa000..=0xafff,
+                #[try_from(other)]
+                Unknown(u16),
+            }
+        );
+
+        assert_eq!(format!("{}", Test::Foo), "FOO");
+        assert_eq!(format!("{:>8}", Test::BarBaz), " bar_baz");
+        assert_eq!(format!("{}", Test::Reserved(0xa001)), "reserved");
+        assert_eq!(format!("{}", Test::Unknown(0x9abc)), "unknown");
+
+        assert_eq!(format!("{:?}", Test::Foo), "FOO(0x1234)");
+        assert_eq!(format!("{:?}", Test::Unknown(0x9abc)), "unknown(0x9abc)");
+        assert_eq!(format!("{:?}", Test::Unknown(0x0800)), "unknown(0x0800)");
+        assert_eq!(format!("{:?}", Test::Unknown(0)), "unknown(0x0000)");
+
+        assert_eq!(format!("{:x}", Test::BarBaz), "5678");
+        assert_eq!(format!("{:#06X}", Test::Reserved(0xa001)), "0xA001");
+
+        impl_enum_try_from!(
+            #[try_from(display)]
+            #[repr(u8)]
+            enum DisplayOnly {
+                Foo = 0x01,
+                #[try_from(rename = "bar")]
+                Bar = 0x02,
+            }
+        );
+
+        assert_eq!(DisplayOnly::Foo.to_string(), "Foo");
+        assert_eq!(DisplayOnly::Bar.to_string(), "bar");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_impl_enum_try_from_serde() {
+        use serde_test::{
+            assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token,
+        };
+
         impl_enum_try_from!(
+            #[try_from(serde, endian = "be")]
             #[repr(u16)]
             #[derive(PartialEq, Eq, Debug)]
+            enum Value {
+                Foo = 0x1234,
+                Bar = 0x5678,
+            }
+        );
+
+        assert_tokens(&Value::Foo, &[Token::U16(0x1234)]);
+        assert_de_tokens_error::<Value>(&[Token::U16(0x9abc)], "invalid value 39612 for `Value`");
+
+        impl_enum_try_from!(
+            #[try_from(serde = "name", from_str = "snake_case")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Name {
+                FooBar = 0x01,
+                #[try_from(rename = "baz", name_alias = "legacy_baz")]
+                Baz = 0x02,
+            }
+        );
+
+        assert_tokens(&Name::FooBar, &[Token::Str("foo_bar")]);
+        assert_tokens(&Name::Baz, &[Token::Str("baz")]);
+        assert_de_tokens(&Name::Baz, &[Token::Str("legacy_baz")]);
+        assert_de_tokens_error::<Name>(
+            &[Token::Str("qux")],
+            "unknown variant `qux`, expected `foo_bar` or `baz`",
+        );
+
+        impl_enum_try_from!(
+            #[try_from(serde = "lenient", from_str = "kebab_case")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Lenient {
+                FooBar = 0x01,
+                #[try_from(other)]
+                Unknown(u8),
+            }
+        );
+
+        assert_tokens(&Lenient::FooBar.readable(), &[Token::Str("foo-bar")]);
+        assert_tokens(&Lenient::FooBar.compact(), &[Token::U8(0x01)]);
+        assert_tokens(&Lenient::Unknown(0x02).readable(), &[Token::U8(0x02)]);
+        assert_de_tokens(&Lenient::FooBar.readable(), &[Token::U64(0x01)]);
+        assert_de_tokens_error::<serde_test::Compact<Lenient>>(
+            &[Token::Str("foo-bar")],
+            "invalid type: string \"foo-bar\", expected u8",
+        );
+        assert_de_tokens_error::<serde_test::Readable<Lenient>>(
+            &[Token::U64(0x100)],
+            "invalid value 256 for `Lenient`",
+        );
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_bytes() {
+        use crate::{TryFromReprError, TryReadError};
+
+        impl_enum_try_from!(
+            #[try_from(endian = "le")]
+            #[repr(u32)]
+            #[derive(PartialEq, Eq, Debug)]
             enum Test {
-                Test = 0x1234,
-                Test2 = 0x5678,
-            },
-            u16,
-            (),
-            ()
+                Foo = 0x12345678,
+                Bar = 0x9abcdef0,
+            }
         );
 
-        assert_eq!(Test::try_from(0x1234), Ok(Test::Test));
-        assert_eq!(Test::try_from(0x5678), Ok(Test::Test2));
+        assert_eq!(Test::try_from([0x78, 0x56, 0x34, 0x12]), Ok(Test::Foo));
+        assert_eq!(
+            Test::try_from_be_bytes([0x12, 0x34, 0x56, 0x78]),
+            Ok(Test::Foo)
+        );
+        assert_eq!(
+            Test::try_from_le_bytes([0xf0, 0xde, 0xbc, 0x9a]),
+            Ok(Test::Bar)
+        );
+        assert_eq!(
+            Test::try_from_ne_bytes(0x9abcdef0_u32.to_ne_bytes()),
+            Ok(Test::Bar)
+        );
+
+        let bytes = [0x78, 0x56, 0x34, 0x12, 0xf0, 0xde, 0xbc, 0x9a, 0x01];
+        let (foo, rest) = Test::try_read_from(&bytes).unwrap();
+        let (bar, rest) = Test::try_read_from(rest).unwrap();
+        assert_eq!((foo, bar), (Test::Foo, Test::Bar));
+        assert_eq!(
+            Test::try_read_from(rest),
+            Err(TryReadError::Truncated {
+                needed: 4,
+                available: 1
+            })
+        );
+        assert_eq!(
+            Test::try_read_from(&[0x01, 0x00, 0x00, 0x00]),
+            Err(TryReadError::Invalid(TryFromReprError {
+                value: 0x01,
+                enum_name: "Test",
+                valid_values: &[0x12345678, 0x9abcdef0],
+                valid_ranges: &[],
+            }))
+        );
+
+        impl_enum_try_from!(
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Other {
+                Foo = 0x01,
+                #[try_from(other)]
+                Unknown(u8),
+            }
+        );
+
+        assert_eq!(
+            Other::try_read_from(&[0x02]),
+            Ok((Other::Unknown(0x02), &[][..]))
+        );
     }
 
+    #[cfg(feature = "zerocopy")]
     #[test]
-    fn test_impl_enum_try_from_be() {
-        impl_enum_try_from_be!(
+    fn test_impl_enum_try_from_zerocopy_crate() {
+        use zerocopy::{IntoBytes, TryFromBytes};
+
+        // `enum_try_from` can't be resolved inside of this crate, the same as
+        // when it's renamed by a dependent crate.
+        impl_enum_try_from!(
+            #[try_from(zerocopy = "crate::__zerocopy")]
+            #[repr(u8)]
+            #[derive(PartialEq, Eq, Debug)]
+            enum Protocol {
+                Tcp = 6,
+                Udp = 17,
+            }
+        );
+
+        assert_eq!(Protocol::try_ref_from_bytes(&[17]), Ok(&Protocol::Udp));
+        assert!(Protocol::try_ref_from_bytes(&[2]).is_err());
+        assert_eq!(Protocol::Tcp.as_bytes(), &[6]);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_impl_enum_try_from_bytemuck() {
+        use bytemuck::{checked, Contiguous};
+
+        impl_enum_try_from!(
+            #[try_from(bytemuck, endian = "be")]
             #[repr(u16)]
+            #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+            enum Checked {
+                #[try_from(alias = 0x0003)]
+                Foo = 0x0001,
+                Bar = 0x0100,
+                Reserved = 0x1000..=0x1fff,
+            }
+        );
+
+        assert_eq!(checked::try_cast::<u16, Checked>(0x0001), Ok(Checked::Foo));
+        assert_eq!(checked::try_cast::<u16, Checked>(0x0100), Ok(Checked::Bar));
+        assert_eq!(
+            checked::try_cast::<u16, Checked>(0x1000),
+            Ok(Checked::Reserved)
+        );
+        // Aliases and the rest of the ranges aren't valid in-place.
+        assert!(checked::try_cast::<u16, Checked>(0x0003).is_err());
+        assert!(checked::try_cast::<u16, Checked>(0x1001).is_err());
+        assert_eq!(bytemuck::cast::<Checked, u16>(Checked::Bar), 0x0100);
+
+        impl_enum_try_from!(
+            #[try_from(bytemuck = "contiguous")]
+            #[repr(i8)]
+            #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+            enum Dense {
+                Foo = -1,
+                Bar = 0,
+                Baz = 1,
+            }
+        );
+
+        assert_eq!((Dense::MIN_VALUE, Dense::MAX_VALUE), (-1, 1));
+        assert_eq!(Dense::from_integer(1), Some(Dense::Baz));
+        assert_eq!(Dense::from_integer(2), None);
+        assert_eq!(Dense::Foo.into_integer(), -1);
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_tag_enum() {
+        use crate::ReadField;
+
+        #[derive(Debug, PartialEq, Eq)]
+        enum TestError {
+            UnknownTag(u16),
+            Payload,
+        }
+
+        impl_enum_try_from!(
+            #[try_from(tag_enum = TestTag, endian = "be", from_str, consts)]
             #[derive(PartialEq, Eq, Debug)]
             enum Test {
-                Test = 0x1234,
-                Test2 = 0x5678,
+                #[tag = 0x1234]
+                Foo(u8, u8),
+                /// Struct variant.
+                #[try_from(alias = 0x9abc)]
+                #[tag = 0x5678]
+                Bar { value: u32 },
+                #[tag = 0xdef0]
+                Baz,
             },
             u16,
-            (),
-            ()
+            TestError,
+            |raw| TestError::UnknownTag(raw)
         );
 
-        assert_eq!(Test::try_from(0x3412), Ok(Test::Test));
-        assert_eq!(Test::try_from(0x7856), Ok(Test::Test2));
+        struct Reader<'a>(&'a [u8]);
+
+        impl ReadField<u8> for Reader<'_> {
+            type Error = TestError;
+
+            fn read_field(&mut self) -> Result<u8, TestError> {
+                let (&byte, rest) = self.0.split_first().ok_or(TestError::Payload)?;
+                self.0 = rest;
+                Ok(byte)
+            }
+        }
+
+        impl ReadField<u32> for Reader<'_> {
+            type Error = TestError;
+
+            fn read_field(&mut self) -> Result<u32, TestError> {
+                let (bytes, rest) = self.0.split_first_chunk().ok_or(TestError::Payload)?;
+                self.0 = rest;
+                Ok(u32::from_le_bytes(*bytes))
+            }
+        }
+
+        assert_eq!(
+            Test::try_from_tag_and(0x1234_u16.to_be(), &mut Reader(&[1, 2])),
+            Ok(Test::Foo(1, 2))
+        );
+        assert_eq!(
+            Test::try_from_tag_and(0x9abc_u16.to_be(), &mut Reader(&[3, 0, 0, 0])),
+            Ok(Test::Bar { value: 3 })
+        );
+        assert_eq!(
+            Test::try_from_tag_and(0xdef0_u16.to_be(), &mut Reader(&[])),
+            Ok(Test::Baz)
+        );
+        // The fields of the variant with the tag are read, even if the
+        // bytes would be enough for another variant.
+        assert_eq!(
+            Test::try_from_tag_and(0x5678_u16.to_be(), &mut Reader(&[1, 2])),
+            Err(TestError::Payload)
+        );
+        assert_eq!(
+            Test::try_from_tag_and(0x0001_u16.to_be(), &mut Reader(&[])),
+            Err(TestError::UnknownTag(0x0001_u16.to_be()))
+        );
+
+        assert_eq!(Test::Foo(1, 2).tag(), TestTag::Foo);
+        assert_eq!(Test::Bar { value: 3 }.tag(), TestTag::Bar);
+        assert_eq!(Test::Baz.tag(), TestTag::Baz);
+
+        assert_eq!(u16::from(TestTag::Bar), 0x5678_u16.to_be());
+        assert_eq!(TestTag::ALL, [TestTag::Foo, TestTag::Bar, TestTag::Baz]);
+        assert_eq!("Baz".parse(), Ok(TestTag::Baz));
+    }
+
+    #[test]
+    fn test_impl_enum_try_from_strategy() {
+        macro_rules! test_strategy {
+            ($strategy:tt) => {{
+                impl_enum_try_from!(
+                    #[try_from(strategy = $strategy)]
+                    #[repr(i16)]
+                    #[derive(PartialEq, Eq, Debug)]
+                    enum Sparse {
+                        None = -0x100,
+                        #[try_from(alias = 0x05)]
+                        Foo = 0x01,
+                        Bar = 0x02,
+                        Reserved(i16) = 0x10..=0x1f,
+                        Baz = 0x7fff,
+                    }
+                );
+
+                assert_eq!(Sparse::try_from(-0x100), Ok(Sparse::None));
+                assert_eq!(Sparse::try_from(0x01), Ok(Sparse::Foo));
+                assert_eq!(Sparse::try_from(0x05), Ok(Sparse::Foo));
+                assert_eq!(Sparse::try_from(0x02), Ok(Sparse::Bar));
+                assert_eq!(Sparse::try_from(0x11), Ok(Sparse::Reserved(0x11)));
+                assert_eq!(Sparse::try_from(0x7fff), Ok(Sparse::Baz));
+                assert!(Sparse::try_from(-0x101).is_err());
+                assert!(Sparse::try_from(0x00).is_err());
+                assert!(Sparse::try_from(0x7ffe).is_err());
+                assert!(Sparse::try_from(i16::MIN).is_err());
+
+                impl_enum_try_from!(
+                    #[try_from(strategy = $strategy)]
+                    #[repr(u8)]
+                    #[derive(PartialEq, Eq, Debug)]
+                    enum Dense {
+                        Foo = 0x03,
+                        Bar = 0x05,
+                        Baz = 0x04,
+                    }
+                );
+
+                assert!(Dense::try_from(0x02).is_err());
+                assert_eq!(Dense::try_from(0x03), Ok(Dense::Foo));
+                assert_eq!(Dense::try_from(0x04), Ok(Dense::Baz));
+                assert_eq!(Dense::try_from(0x05), Ok(Dense::Bar));
+                assert!(Dense::try_from(0x06).is_err());
+                assert!(Dense::try_from(u8::MAX).is_err());
+            }};
+        }
+
+        test_strategy!("auto");
+        test_strategy!("match");
+        test_strategy!("table");
+        test_strategy!("binary_search");
+    }
+
+    #[test]
+    fn test_impl_flags_try_from() {
+        extern crate std;
+        use std::{format, vec::Vec};
+
+        impl_flags_try_from!(
+            #[repr(u8)]
+            #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+            enum Flag {
+                Foo = 0x01,
+                Bar = 0x02,
+                Baz = 0x80,
+            },
+            Flags
+        );
+
+        let flags = Flags::try_from(0x83).unwrap();
+        assert!(flags.contains(Flag::Foo));
+        assert!(flags.contains(Flag::Bar));
+        assert!(flags.contains(Flag::Baz));
+        assert_eq!(flags, Flags::all());
+        assert_eq!(
+            flags.iter().collect::<Vec<_>>(),
+            [Flag::Foo, Flag::Bar, Flag::Baz]
+        );
+        assert_eq!(format!("{flags:?}"), "Flags(Foo | Bar | Baz)");
+        assert_eq!(format!("{:?}", Flags::empty()), "Flags()");
+
+        assert_eq!(
+            Flags::try_from(0x45),
+            Err(crate::TryFromFlagsError {
+                value: 0x45,
+                unknown_bits: 0x44,
+                flags_name: "Flags",
+            })
+        );
+        assert_eq!(Flags::from_bits_truncate(0x45), Flags::from(Flag::Foo));
+
+        let mut flags = Flag::Foo | Flag::Baz;
+        assert_eq!(flags.bits(), 0x81);
+        assert_eq!(flags & Flag::Baz, Flags::from(Flag::Baz));
+        assert_eq!(flags & Flags::from(Flag::Bar), Flags::empty());
+        flags |= Flag::Bar;
+        flags.remove(Flag::Foo);
+        assert_eq!(u8::from(flags), 0x82);
+        flags &= Flags::from(Flag::Bar);
+        assert_eq!(flags.iter().collect::<Flags>(), Flags::from(Flag::Bar));
+        assert!(!flags.is_empty());
+
+        assert_eq!(Flag::try_from(0x80), Ok(Flag::Baz));
+        assert!(Flag::try_from(0x03).is_err());
+    }
+
+    #[test]
+    fn test_impl_flags_try_from_be() {
+        impl_flags_try_from_be!(
+            #[repr(u16)]
+            #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+            enum Flag {
+                Foo = 0x0001,
+                Bar = 0x0100,
+            },
+            Flags
+        );
+
+        let flags = Flags::try_from(0x0101_u16.to_be()).unwrap();
+        assert_eq!(flags, Flag::Foo | Flag::Bar);
+        assert_eq!(flags.bits(), 0x0101);
+        assert_eq!(u16::from(Flag::Foo | Flag::Bar), 0x0101_u16.to_be());
+        assert_eq!(
+            Flags::from_bits_truncate(0x0103_u16.to_be()),
+            Flag::Foo | Flag::Bar
+        );
+        assert!(Flags::try_from(0x0103_u16.to_be()).is_err());
     }
 }